@@ -324,6 +324,7 @@ impl<T: ?Sized> Box<T> {
     /// let ptr = Box::into_raw(x);
     /// ```
     #[stable(feature = "box_raw", since = "1.4.0")]
+    #[cfg_attr(not(stage0), lang = "box_into_raw")]
     #[inline]
     pub fn into_raw(b: Box<T>) -> *mut T {
         Box::into_unique(b).as_ptr()
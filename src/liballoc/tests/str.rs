@@ -1312,6 +1312,20 @@ fn test_box_slice_clone() {
     assert_eq!(data, data2);
 }
 
+#[test]
+fn test_box_slice_clone_bytes() {
+    let empty: Box<str> = String::new().into_boxed_str();
+    let empty2 = empty.clone();
+    assert_eq!(empty2.len(), 0);
+    assert_eq!(empty.as_bytes(), empty2.as_bytes());
+
+    let multi: Box<str> = String::from("ß中𝄞").into_boxed_str();
+    let multi2 = multi.clone();
+    assert_eq!(multi.as_bytes(), multi2.as_bytes());
+    assert_eq!(multi2.as_bytes(), b"\xc3\x9f\xe4\xb8\xad\xf0\x9d\x84\x9e");
+    assert!(multi.as_ptr() != multi2.as_ptr());
+}
+
 #[test]
 fn test_cow_from() {
     let borrowed = "borrowed";
@@ -171,6 +171,7 @@ pub use intrinsics::transmute;
 /// [ub]: ../../reference/behavior-considered-undefined.html
 #[inline]
 #[stable(feature = "rust1", since = "1.0.0")]
+#[cfg_attr(not(stage0), lang = "forget")]
 pub fn forget<T>(t: T) {
     ManuallyDrop::new(t);
 }
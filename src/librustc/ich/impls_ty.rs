@@ -114,6 +114,12 @@ for ty::adjustment::Adjust<'gcx> {
             ty::adjustment::Adjust::UnsafeFnPointer |
             ty::adjustment::Adjust::ClosureFnPointer |
             ty::adjustment::Adjust::MutToConstPointer |
+            ty::adjustment::Adjust::ArrayToPointer |
+            ty::adjustment::Adjust::Box |
+            ty::adjustment::Adjust::BorrowArrayElements |
+            ty::adjustment::Adjust::WrapUnit |
+            ty::adjustment::Adjust::BoxIntoRaw |
+            ty::adjustment::Adjust::ReinterpretSlice |
             ty::adjustment::Adjust::Unsize => {}
             ty::adjustment::Adjust::Deref(ref overloaded) => {
                 overloaded.hash_stable(hcx, hasher);
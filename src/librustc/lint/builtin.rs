@@ -76,6 +76,12 @@ declare_lint! {
     "detects unreachable patterns"
 }
 
+declare_lint! {
+    pub IRREFUTABLE_MATCH,
+    Warn,
+    "detects `match` expressions on an irrefutable pattern with an `unreachable!()` fallback"
+}
+
 declare_lint! {
     pub UNUSED_MACROS,
     Warn,
@@ -118,6 +124,24 @@ declare_lint! {
     "detects trivial casts which could be removed"
 }
 
+declare_lint! {
+    pub IMPLICIT_MUT_TO_CONST_POINTER,
+    Allow,
+    "detects `*mut T` implicitly coerced to `*const T`"
+}
+
+declare_lint! {
+    pub FORGET_WHILE_DROP_LIVE,
+    Warn,
+    "detects locals that are still dropped after a call to `mem::forget` (only with `-Z nll`)"
+}
+
+declare_lint! {
+    pub MUT_TEMPORARY_ARRAY_COERCION,
+    Allow,
+    "detects `&mut` array literals coerced to slices that are dropped right away"
+}
+
 declare_lint! {
     pub TRIVIAL_NUMERIC_CASTS,
     Allow,
@@ -245,6 +269,7 @@ impl LintPass for HardwiredLints {
             DEAD_CODE,
             UNREACHABLE_CODE,
             UNREACHABLE_PATTERNS,
+            IRREFUTABLE_MATCH,
             UNUSED_MACROS,
             WARNINGS,
             UNUSED_FEATURES,
@@ -253,6 +278,9 @@ impl LintPass for HardwiredLints {
             FAT_PTR_TRANSMUTES,
             TRIVIAL_CASTS,
             TRIVIAL_NUMERIC_CASTS,
+            IMPLICIT_MUT_TO_CONST_POINTER,
+            MUT_TEMPORARY_ARRAY_COERCION,
+            FORGET_WHILE_DROP_LIVE,
             PRIVATE_IN_PUBLIC,
             PUB_USE_OF_PRIVATE_EXTERN_CRATE,
             INVALID_TYPE_PARAM_DEFAULT,
@@ -710,9 +710,16 @@ impl<'a, 'gcx, 'tcx> ExprUseVisitor<'a, 'gcx, 'tcx> {
                 adjustment::Adjust::UnsafeFnPointer |
                 adjustment::Adjust::ClosureFnPointer |
                 adjustment::Adjust::MutToConstPointer |
+                adjustment::Adjust::ArrayToPointer |
+                adjustment::Adjust::Box |
+                adjustment::Adjust::BorrowArrayElements |
+                adjustment::Adjust::WrapUnit |
+                adjustment::Adjust::BoxIntoRaw |
+                adjustment::Adjust::ReinterpretSlice |
                 adjustment::Adjust::Unsize => {
-                    // Creating a closure/fn-pointer or unsizing consumes
-                    // the input and stores it into the resulting rvalue.
+                    // Creating a closure/fn-pointer, boxing, wrapping,
+                    // leaking, reinterpreting or unsizing consumes the input
+                    // and stores it into the resulting rvalue.
                     self.delegate_consume(expr.id, expr.span, cmt.clone());
                 }
 
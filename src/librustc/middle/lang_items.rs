@@ -295,6 +295,7 @@ language_item_table! {
 
     ExchangeMallocFnLangItem,        "exchange_malloc",         exchange_malloc_fn;
     BoxFreeFnLangItem,               "box_free",                box_free_fn;
+    BoxIntoRawFnLangItem,            "box_into_raw",            box_into_raw_fn;
     DropInPlaceFnLangItem,             "drop_in_place",           drop_in_place_fn;
 
     StartFnLangItem,                 "start",                   start_fn;
@@ -310,6 +311,12 @@ language_item_table! {
     NonZeroItem,                     "non_zero",                non_zero;
 
     DebugTraitLangItem,              "debug_trait",             debug_trait;
+
+    FromTraitLangItem,               "from",                    from_trait;
+
+    ForgetFnLangItem,                "forget",                  forget_fn;
+
+    IteratorTraitLangItem,           "iterator",                iterator_trait;
 }
 
 impl<'a, 'tcx, 'gcx> TyCtxt<'a, 'tcx, 'gcx> {
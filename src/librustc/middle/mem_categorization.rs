@@ -588,6 +588,12 @@ impl<'a, 'gcx, 'tcx> MemCategorizationContext<'a, 'gcx, 'tcx> {
             adjustment::Adjust::UnsafeFnPointer |
             adjustment::Adjust::ClosureFnPointer |
             adjustment::Adjust::MutToConstPointer |
+            adjustment::Adjust::ArrayToPointer |
+            adjustment::Adjust::Box |
+            adjustment::Adjust::BorrowArrayElements |
+            adjustment::Adjust::WrapUnit |
+            adjustment::Adjust::BoxIntoRaw |
+            adjustment::Adjust::ReinterpretSlice |
             adjustment::Adjust::Borrow(_) |
             adjustment::Adjust::Unsize => {
                 // Result is an rvalue.
@@ -62,6 +62,14 @@ pub enum Sanitizer {
     Thread,
 }
 
+/// Whether coercion tries unsizing before auto-borrowing, or the other way
+/// around (`-Z coercion-order`).
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum CoercionOrder {
+    UnsizeFirst,
+    BorrowFirst,
+}
+
 #[derive(Clone, Copy, PartialEq, Hash)]
 pub enum OptLevel {
     No, // -O0
@@ -682,6 +690,8 @@ macro_rules! options {
             Some("one of: `full`, `partial`, or `off`");
         pub const parse_sanitizer: Option<&'static str> =
             Some("one of: `address`, `leak`, `memory` or `thread`");
+        pub const parse_coercion_order: Option<&'static str> =
+            Some("either `unsize-first` or `borrow-first`");
         pub const parse_linker_flavor: Option<&'static str> =
             Some(::rustc_back::LinkerFlavor::one_of());
         pub const parse_optimization_fuel: Option<&'static str> =
@@ -690,7 +700,7 @@ macro_rules! options {
 
     #[allow(dead_code)]
     mod $mod_set {
-        use super::{$struct_name, Passes, SomePasses, AllPasses, Sanitizer};
+        use super::{$struct_name, Passes, SomePasses, AllPasses, Sanitizer, CoercionOrder};
         use rustc_back::{LinkerFlavor, PanicStrategy, RelroLevel};
 
         $(
@@ -836,6 +846,15 @@ macro_rules! options {
             true
         }
 
+        fn parse_coercion_order(slot: &mut Option<CoercionOrder>, v: Option<&str>) -> bool {
+            match v {
+                Some("unsize-first") => *slot = Some(CoercionOrder::UnsizeFirst),
+                Some("borrow-first") => *slot = Some(CoercionOrder::BorrowFirst),
+                _ => return false,
+            }
+            true
+        }
+
         fn parse_linker_flavor(slote: &mut Option<LinkerFlavor>, v: Option<&str>) -> bool {
             match v.and_then(LinkerFlavor::from_str) {
                 Some(lf) => *slote = Some(lf),
@@ -1064,6 +1083,10 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
            contains unsafe block, only validate arguments; 2: always emit full validation)"),
     perf_stats: bool = (false, parse_bool, [UNTRACKED],
           "print some performance-related statistics"),
+    coercion_stats: bool = (false, parse_bool, [UNTRACKED],
+          "print the number of coercions attempted and applied while type checking"),
+    dump_coercions: bool = (false, parse_bool, [UNTRACKED],
+          "print every implicit coercion in the crate along with its adjustments"),
     hir_stats: bool = (false, parse_bool, [UNTRACKED],
           "print some statistics about AST and HIR"),
     mir_stats: bool = (false, parse_bool, [UNTRACKED],
@@ -1096,6 +1119,26 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
         "choose which RELRO level to use"),
     nll: bool = (false, parse_bool, [UNTRACKED],
                  "run the non-lexical lifetimes MIR pass"),
+    dump_liveness_stats: bool = (false, parse_bool, [UNTRACKED],
+        "print the size and number of fixpoint rounds of each NLL liveness computation"),
+    dump_dead_stores: bool = (false, parse_bool, [UNTRACKED],
+        "mark assignments to locals that are never read afterwards in the NLL MIR dump"),
+    dump_liveness_kills: bool = (false, parse_bool, [UNTRACKED],
+        "mark where and why each local stops being live in the NLL MIR dump"),
+    verify_liveness: bool = (false, parse_bool, [UNTRACKED],
+        "check the NLL liveness results against a round-robin fixpoint over all blocks"),
+    verify_liveness_update: bool = (false, parse_bool, [UNTRACKED],
+        "check that updating NLL liveness after editing a block matches recomputing it"),
+    verify_storage_markers: bool = (false, parse_bool, [UNTRACKED],
+        "check that no local is live outside of its storage markers in the NLL pass"),
+    verify_drops: bool = (false, parse_bool, [UNTRACKED],
+        "check that no local is used after it is dropped in the NLL pass"),
+    nll_facts: bool = (false, parse_bool, [UNTRACKED],
+        "write the NLL liveness results out as tab-separated fact files"),
+    nll_facts_dir: String = ("nll-facts".to_string(), parse_string, [UNTRACKED],
+        "the directory the NLL facts are written into (default: `nll-facts`)"),
+    coercion_order: Option<CoercionOrder> = (None, parse_coercion_order, [TRACKED],
+        "whether coercions try unsizing or auto-borrowing first (default: unsize-first)"),
     trans_time_graph: bool = (false, parse_bool, [UNTRACKED],
         "generate a graphical HTML report of time spent in trans and LLVM"),
     thinlto: bool = (false, parse_bool, [TRACKED],
@@ -1855,7 +1898,7 @@ mod dep_tracking {
     use std::path::PathBuf;
     use std::collections::hash_map::DefaultHasher;
     use super::{Passes, CrateType, OptLevel, DebugInfoLevel,
-                OutputTypes, Externs, ErrorOutputType, Sanitizer};
+                OutputTypes, Externs, ErrorOutputType, Sanitizer, CoercionOrder};
     use syntax::feature_gate::UnstableFeatures;
     use rustc_back::{PanicStrategy, RelroLevel};
 
@@ -1915,6 +1958,8 @@ mod dep_tracking {
     impl_dep_tracking_hash_via_hash!(cstore::NativeLibraryKind);
     impl_dep_tracking_hash_via_hash!(Sanitizer);
     impl_dep_tracking_hash_via_hash!(Option<Sanitizer>);
+    impl_dep_tracking_hash_via_hash!(CoercionOrder);
+    impl_dep_tracking_hash_via_hash!(Option<CoercionOrder>);
 
     impl_dep_tracking_hash_for_sortable_vec_of!(String);
     impl_dep_tracking_hash_for_sortable_vec_of!(CrateType);
@@ -1984,7 +2029,7 @@ mod tests {
     use std::collections::{BTreeMap, BTreeSet};
     use std::iter::FromIterator;
     use std::path::PathBuf;
-    use super::{OutputType, OutputTypes, Externs};
+    use super::{OutputType, OutputTypes, Externs, CoercionOrder};
     use rustc_back::{PanicStrategy, RelroLevel};
     use syntax::symbol::Symbol;
 
@@ -2658,5 +2703,9 @@ mod tests {
         opts = reference.clone();
         opts.debugging_opts.relro_level = Some(RelroLevel::Full);
         assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
+
+        opts = reference.clone();
+        opts.debugging_opts.coercion_order = Some(CoercionOrder::BorrowFirst);
+        assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
     }
 }
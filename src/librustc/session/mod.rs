@@ -40,7 +40,7 @@ use rustc_data_structures::flock;
 use jobserver::Client;
 
 use std::cell::{self, Cell, RefCell};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::fmt;
 use std::io::Write;
@@ -118,6 +118,11 @@ pub struct Session {
     /// Data about code being compiled, gathered during compilation.
     pub code_stats: RefCell<CodeStats>,
 
+    /// The implicit coercions applied while type checking, keyed by the
+    /// span of the coerced expression. Only filled in with
+    /// `-Z dump-coercions`.
+    pub coercion_sites: RefCell<BTreeMap<Span, String>>,
+
     next_node_id: Cell<ast::NodeId>,
 
     /// If -zfuel=crate=n is specified, Some(crate).
@@ -155,6 +160,10 @@ pub struct PerfStats {
     pub symbol_hash_time: Cell<Duration>,
     /// The accumulated time spent decoding def path tables from metadata
     pub decode_def_path_tables_time: Cell<Duration>,
+    /// The number of coercions attempted by type checking
+    pub coercion_attempts: Cell<u64>,
+    /// The number of those coercions that succeeded with adjustments
+    pub coercion_adjustments: Cell<u64>,
 }
 
 /// Enum to support dispatch of one-time diagnostics (in Session.diag_once)
@@ -613,6 +622,27 @@ impl Session {
                  duration_to_secs_str(self.perf_stats.symbol_hash_time.get()));
         println!("Total time spent decoding DefPath tables:      {}",
                  duration_to_secs_str(self.perf_stats.decode_def_path_tables_time.get()));
+        self.print_coercion_stats();
+    }
+
+    pub fn print_coercion_stats(&self) {
+        println!("Total number of coercions attempted:           {}",
+                 self.perf_stats.coercion_attempts.get());
+        println!("Total number of coercions with adjustments:    {}",
+                 self.perf_stats.coercion_adjustments.get());
+    }
+
+    /// Records the adjustments applied at a coercion site for
+    /// `-Z dump-coercions`. A site that is coerced more than once, e.g.
+    /// while computing the LUB of match arms, keeps its last coercion.
+    pub fn record_coercion_site(&self, span: Span, kind: String) {
+        self.coercion_sites.borrow_mut().insert(span, kind);
+    }
+
+    pub fn print_coercion_sites(&self) {
+        for (&span, kind) in self.coercion_sites.borrow().iter() {
+            println!("coercion at {}: {}", self.codemap().span_to_string(span), kind);
+        }
     }
 
     /// We want to know if we're allowed to do an optimization for crate foo from -z fuel=foo=n.
@@ -816,8 +846,11 @@ pub fn build_session_(sopts: config::Options,
             incr_comp_bytes_hashed: Cell::new(0),
             symbol_hash_time: Cell::new(Duration::from_secs(0)),
             decode_def_path_tables_time: Cell::new(Duration::from_secs(0)),
+            coercion_attempts: Cell::new(0),
+            coercion_adjustments: Cell::new(0),
         },
         code_stats: RefCell::new(CodeStats::new()),
+        coercion_sites: RefCell::new(BTreeMap::new()),
         optimization_fuel_crate,
         optimization_fuel_limit,
         print_fuel_crate,
@@ -658,7 +658,7 @@ fn vtable_methods<'a, 'tcx>(
     debug!("vtable_methods({:?})", trait_ref);
 
     Rc::new(
-        supertraits(tcx, trait_ref).flat_map(move |trait_ref| {
+        util::vtable_supertraits(tcx, trait_ref).into_iter().flat_map(move |trait_ref| {
             let trait_methods = tcx.associated_items(trait_ref.def_id())
                 .filter(|item| item.kind == ty::AssociatedKind::Method);
 
@@ -1843,19 +1843,22 @@ impl<'cx, 'gcx, 'tcx> SelectionContext<'cx, 'gcx, 'tcx> {
         let may_apply = match (&source.sty, &target.sty) {
             // Trait+Kx+'a -> Trait+Ky+'b (upcasts).
             (&ty::TyDynamic(ref data_a, ..), &ty::TyDynamic(ref data_b, ..)) => {
-                // Upcasts permit two things:
+                // Upcasts permit three things:
                 //
                 // 1. Dropping builtin bounds, e.g. `Foo+Send` to `Foo`
                 // 2. Tightening the region bound, e.g. `Foo+'a` to `Foo+'b` if `'a : 'b`
+                // 3. Moving to a supertrait, e.g. `Foo` to `Bar` if `Foo: Bar`
                 //
-                // Note that neither of these changes requires any
-                // change at runtime.  Eventually this will be
-                // generalized.
+                // Note that none of these changes requires any change
+                // at runtime. For #3, confirmation checks that the
+                // vtable of `Foo` starts with the vtable of `Bar`.
                 //
                 // We always upcast when we can because of reason
                 // #2 (region bounds).
                 match (data_a.principal(), data_b.principal()) {
-                    (Some(a), Some(b)) => a.def_id() == b.def_id() &&
+                    (Some(a), Some(b)) =>
+                        util::supertrait_def_ids(self.tcx(), a.def_id())
+                            .any(|did| did == b.def_id()) &&
                         data_b.auto_traits()
                             // All of a's auto traits need to be in b's auto traits.
                             .all(|b| data_a.auto_traits().any(|a| a == b)),
@@ -2566,9 +2569,11 @@ impl<'cx, 'gcx, 'tcx> SelectionContext<'cx, 'gcx, 'tcx> {
             // unification. We know that there is exactly one in the list
             // where we can unify because otherwise select would have
             // reported an ambiguity. (When we do find a match, also
-            // record it for later.)
+            // record it for later.) The list is in vtable order, see
+            // `util::vtable_supertraits`.
             let nonmatching =
-                util::supertraits(tcx, poly_trait_ref)
+                util::vtable_supertraits(tcx, poly_trait_ref)
+                .into_iter()
                 .take_while(|&t| {
                     match
                         self.commit_if_ok(
@@ -2786,6 +2791,52 @@ impl<'cx, 'gcx, 'tcx> SelectionContext<'cx, 'gcx, 'tcx> {
         let mut nested = vec![];
         match (&source.sty, &target.sty) {
             // Trait+Kx+'a -> Trait+Ky+'b (upcasts).
+            (&ty::TyDynamic(ref data_a, r_a), &ty::TyDynamic(ref data_b, r_b))
+                if data_a.principal().map(|p| p.def_id()) !=
+                   data_b.principal().map(|p| p.def_id()) => {
+                // Supertrait upcast, see assemble_candidates_for_unsizing.
+                let principal_a = data_a.principal().unwrap().with_self_ty(tcx, source);
+                let principal_b = data_b.principal().unwrap();
+                if !tcx.is_object_safe(principal_b.def_id()) {
+                    return Err(TraitNotObjectSafe(principal_b.def_id()));
+                }
+
+                // `Foo: Bar<u8> + Bar<u16>` can't be upcast to `Bar<_>`
+                // without knowing which of the two is meant.
+                let upcast_trait_ref =
+                    match &tcx.upcast_choices(principal_a, principal_b.def_id())[..] {
+                        &[upcast_trait_ref] => upcast_trait_ref,
+                        _ => return Err(Unimplemented),
+                    };
+                let InferOk { obligations, .. } =
+                    self.infcx.at(&obligation.cause, obligation.param_env)
+                              .eq(principal_b.with_self_ty(tcx, source), upcast_trait_ref)
+                              .map_err(|_| Unimplemented)?;
+                self.inferred_obligations.extend(obligations);
+
+                // Trans keeps the vtable of the source object, so it must
+                // start with the entries of the target's vtable.
+                if !tcx.is_vtable_prefix(principal_a, upcast_trait_ref) {
+                    return Err(Unimplemented);
+                }
+
+                // The source object has to satisfy the projection bounds
+                // of the target, and its region has to outlive the
+                // target's. Its auto traits were checked during assembly.
+                let cause = ObligationCause::new(obligation.cause.span,
+                                                 obligation.cause.body_id,
+                                                 ObjectCastObligation(target));
+                let predicates = data_b.projection_bounds()
+                    .map(|p| p.with_self_ty(tcx, source).to_predicate())
+                    .chain(Some(ty::Binder(ty::OutlivesPredicate(r_a, r_b)).to_predicate()));
+                for predicate in predicates {
+                    nested.push(Obligation::with_depth(cause.clone(),
+                                                       obligation.recursion_depth + 1,
+                                                       obligation.param_env,
+                                                       predicate));
+                }
+            }
+
             (&ty::TyDynamic(ref data_a, r_a), &ty::TyDynamic(ref data_b, r_b)) => {
                 // See assemble_candidates_for_unsizing for more info.
                 // Binders reintroduced below in call to mk_existential_predicates.
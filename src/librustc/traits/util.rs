@@ -274,6 +274,40 @@ pub fn supertraits<'cx, 'gcx, 'tcx>(tcx: TyCtxt<'cx, 'gcx, 'tcx>,
     elaborate_trait_ref(tcx, trait_ref).filter_to_traits()
 }
 
+/// Returns `trait_ref` and its supertraits in the order their methods
+/// are laid out in the vtable of a `trait_ref` object. Each trait comes
+/// after its supertraits, which are visited in declaration order, so
+/// the layout for a trait's first supertrait is a prefix of its own.
+/// That is what allows upcasting an object to that supertrait while
+/// keeping its vtable.
+pub fn vtable_supertraits<'cx, 'gcx, 'tcx>(tcx: TyCtxt<'cx, 'gcx, 'tcx>,
+                                           trait_ref: ty::PolyTraitRef<'tcx>)
+                                           -> Vec<ty::PolyTraitRef<'tcx>>
+{
+    fn visit<'cx, 'gcx, 'tcx>(visited: &mut PredicateSet<'cx, 'gcx, 'tcx>,
+                              trait_ref: ty::PolyTraitRef<'tcx>,
+                              traits: &mut Vec<ty::PolyTraitRef<'tcx>>) {
+        let tcx = visited.tcx;
+        for predicate in &tcx.super_predicates_of(trait_ref.def_id()).predicates {
+            let predicate = predicate.subst_supertrait(tcx, &trait_ref);
+            if let Some(supertrait) = predicate.to_opt_poly_trait_ref() {
+                // As in `Elaborator`, skip traits we have already seen,
+                // so each one appears once.
+                if visited.insert(&predicate) {
+                    visit(visited, supertrait, traits);
+                }
+            }
+        }
+        traits.push(trait_ref);
+    }
+
+    let mut visited = PredicateSet::new(tcx);
+    visited.insert(&trait_ref.to_predicate());
+    let mut traits = vec![];
+    visit(&mut visited, trait_ref, &mut traits);
+    traits
+}
+
 pub fn transitive_bounds<'cx, 'gcx, 'tcx>(tcx: TyCtxt<'cx, 'gcx, 'tcx>,
                                           bounds: &[ty::PolyTraitRef<'tcx>])
                                           -> Supertraits<'cx, 'gcx, 'tcx>
@@ -461,6 +495,22 @@ impl<'a, 'gcx, 'tcx> TyCtxt<'a, 'gcx, 'tcx> {
             .collect()
     }
 
+    /// Whether the vtable of a `source_trait_ref` object starts with the
+    /// vtable of `target_trait_ref`, one of its supertraits, so that the
+    /// object can be upcast to `target_trait_ref` by keeping its vtable.
+    pub fn is_vtable_prefix(self,
+                            source_trait_ref: ty::PolyTraitRef<'tcx>,
+                            target_trait_ref: ty::PolyTraitRef<'tcx>)
+                            -> bool
+    {
+        let source = vtable_supertraits(self, source_trait_ref);
+        let target = vtable_supertraits(self, target_trait_ref);
+        target.len() <= source.len() &&
+            source.iter().zip(&target).all(|(a, b)| {
+                self.anonymize_late_bound_regions(a) == self.anonymize_late_bound_regions(b)
+            })
+    }
+
     /// Given a trait `trait_ref`, returns the number of vtable entries
     /// that come from `trait_ref`, excluding its supertraits. Used in
     /// computing the vtable base for an upcast trait of a trait object.
@@ -77,6 +77,34 @@ pub enum Adjust<'tcx> {
     /// Go from a mut raw pointer to a const raw pointer.
     MutToConstPointer,
 
+    /// Go from a raw pointer to an array to a raw pointer to its first
+    /// element, e.g. `*const [T; n]` to `*const T`.
+    ArrayToPointer,
+
+    /// Move the value into a new heap allocation, producing a `Box<T>`.
+    /// Only introduced by the feature-gated coercion of an array to a
+    /// boxed slice in a `let` initializer.
+    Box,
+
+    /// Go from a shared reference to an array to a tuple of shared
+    /// references to each of its elements, e.g. `&'a [T; 2]` to
+    /// `(&'a T, &'a T)`.
+    BorrowArrayElements,
+
+    /// Go from `()` to a struct whose only field is `()`, by constructing
+    /// the struct around it. The target type is the struct.
+    WrapUnit,
+
+    /// Go from a `Box<T>` to a `*mut T` by calling `Box::into_raw`, which
+    /// leaks the box: ownership of the allocation passes to the pointer.
+    BoxIntoRaw,
+
+    /// Go from a shared reference to a slice to a shared reference to a
+    /// slice of another integer or float type with the same size and
+    /// alignment, e.g. `&[u8]` to `&[i8]`, reinterpreting the elements in
+    /// place. Only introduced by a feature-gated coercion.
+    ReinterpretSlice,
+
     /// Dereference once, producing an lvalue.
     Deref(Option<OverloadedDeref<'tcx>>),
 
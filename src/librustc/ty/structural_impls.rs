@@ -302,6 +302,18 @@ impl<'a, 'tcx> Lift<'tcx> for ty::adjustment::Adjust<'a> {
                 Some(ty::adjustment::Adjust::ClosureFnPointer),
             ty::adjustment::Adjust::MutToConstPointer =>
                 Some(ty::adjustment::Adjust::MutToConstPointer),
+            ty::adjustment::Adjust::ArrayToPointer =>
+                Some(ty::adjustment::Adjust::ArrayToPointer),
+            ty::adjustment::Adjust::Box =>
+                Some(ty::adjustment::Adjust::Box),
+            ty::adjustment::Adjust::BorrowArrayElements =>
+                Some(ty::adjustment::Adjust::BorrowArrayElements),
+            ty::adjustment::Adjust::WrapUnit =>
+                Some(ty::adjustment::Adjust::WrapUnit),
+            ty::adjustment::Adjust::BoxIntoRaw =>
+                Some(ty::adjustment::Adjust::BoxIntoRaw),
+            ty::adjustment::Adjust::ReinterpretSlice =>
+                Some(ty::adjustment::Adjust::ReinterpretSlice),
             ty::adjustment::Adjust::Unsize =>
                 Some(ty::adjustment::Adjust::Unsize),
             ty::adjustment::Adjust::Deref(ref overloaded) => {
@@ -865,6 +877,12 @@ impl<'tcx> TypeFoldable<'tcx> for ty::adjustment::Adjust<'tcx> {
             ty::adjustment::Adjust::UnsafeFnPointer |
             ty::adjustment::Adjust::ClosureFnPointer |
             ty::adjustment::Adjust::MutToConstPointer |
+            ty::adjustment::Adjust::ArrayToPointer |
+            ty::adjustment::Adjust::Box |
+            ty::adjustment::Adjust::BorrowArrayElements |
+            ty::adjustment::Adjust::WrapUnit |
+            ty::adjustment::Adjust::BoxIntoRaw |
+            ty::adjustment::Adjust::ReinterpretSlice |
             ty::adjustment::Adjust::Unsize => self.clone(),
             ty::adjustment::Adjust::Deref(ref overloaded) => {
                 ty::adjustment::Adjust::Deref(overloaded.fold_with(folder))
@@ -882,6 +900,12 @@ impl<'tcx> TypeFoldable<'tcx> for ty::adjustment::Adjust<'tcx> {
             ty::adjustment::Adjust::UnsafeFnPointer |
             ty::adjustment::Adjust::ClosureFnPointer |
             ty::adjustment::Adjust::MutToConstPointer |
+            ty::adjustment::Adjust::ArrayToPointer |
+            ty::adjustment::Adjust::Box |
+            ty::adjustment::Adjust::BorrowArrayElements |
+            ty::adjustment::Adjust::WrapUnit |
+            ty::adjustment::Adjust::BoxIntoRaw |
+            ty::adjustment::Adjust::ReinterpretSlice |
             ty::adjustment::Adjust::Unsize => false,
             ty::adjustment::Adjust::Deref(ref overloaded) => {
                 overloaded.visit_with(visitor)
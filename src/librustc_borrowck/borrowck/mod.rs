@@ -905,9 +905,19 @@ impl<'a, 'tcx> BorrowckCtxt<'a, 'tcx> {
                 }
 
                 let mut db = self.path_does_not_live_long_enough(error_span, &msg, Origin::Ast);
+                // Borrows inserted by a coercion or an autoderef have no `&`
+                // in the source, so say that the borrow was implicit.
+                let is_implicit = match cause {
+                    euv::AutoRef => !self.is_autoref_receiver(err.cmt.id),
+                    _ => false,
+                };
                 let (value_kind, value_msg) = match err.cmt.cat {
+                    mc::Categorization::Rvalue(..) if is_implicit =>
+                        ("temporary value", "temporary value implicitly borrowed here"),
                     mc::Categorization::Rvalue(..) =>
                         ("temporary value", "temporary value created here"),
+                    _ if is_implicit =>
+                        ("borrowed value", "implicit borrow occurs here"),
                     _ =>
                         ("borrowed value", "borrow occurs here")
                 };
@@ -1185,6 +1195,24 @@ impl<'a, 'tcx> BorrowckCtxt<'a, 'tcx> {
         }
     }
 
+    /// Whether `node_id` is the receiver of a method call or the operand
+    /// of an overloaded operator, where an autoref is expected and not
+    /// worth pointing out.
+    fn is_autoref_receiver(&self, node_id: ast::NodeId) -> bool {
+        let parent = self.tcx.hir.get_parent_node(node_id);
+        match self.tcx.hir.find(parent) {
+            Some(hir_map::NodeExpr(expr)) => match expr.node {
+                hir::ExprMethodCall(_, _, ref args) => args[0].id == node_id,
+                hir::ExprIndex(..) |
+                hir::ExprUnary(..) |
+                hir::ExprBinary(..) |
+                hir::ExprAssignOp(..) => true,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
     fn note_immutability_blame(&self,
                                db: &mut DiagnosticBuilder,
                                blame: Option<ImmutabilityBlame>) {
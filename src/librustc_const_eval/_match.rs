@@ -208,10 +208,10 @@ impl<'a, 'tcx> MatchCheckCtxt<'a, 'tcx> {
         }
     }
 
-    fn is_variant_uninhabited(&self,
-                              variant: &'tcx ty::VariantDef,
-                              substs: &'tcx ty::subst::Substs<'tcx>)
-                              -> bool
+    pub fn is_variant_uninhabited(&self,
+                                  variant: &'tcx ty::VariantDef,
+                                  substs: &'tcx ty::subst::Substs<'tcx>)
+                                  -> bool
     {
         if self.tcx.sess.features.borrow().never_type {
             self.tcx.is_enum_variant_uninhabited_from(self.module, variant, substs)
@@ -388,6 +388,251 @@ impl<'tcx> Witness<'tcx> {
     }
 }
 
+/// Turns a witness for `pattern` being refutable into a more tangible
+/// counterexample: wildcards in `witness` are replaced by a value escaping
+/// the corresponding part of `pattern`, or by an arbitrary value once the
+/// witness has diverged from `pattern`. Wildcards where `pattern` accepts
+/// any value, such as a binding, are left alone, as are those for which no
+/// value can be picked.
+///
+/// pattern: (1, (Some(1), 2...3))
+/// witness: (_, _)                 => (0, (None, 0))
+///
+/// pattern: (true, x)
+/// witness: (false, _)             => (false, _)
+pub fn concretize_witness<'a, 'tcx>(cx: &MatchCheckCtxt<'a, 'tcx>,
+                                    witness: &Pattern<'tcx>,
+                                    pattern: &Pattern<'tcx>)
+                                    -> Pattern<'tcx>
+{
+    let kind = match (&*witness.kind, &*pattern.kind) {
+        (_, &PatternKind::Binding { subpattern: Some(ref subpattern), .. }) => {
+            return concretize_witness(cx, witness, subpattern);
+        }
+        (_, &PatternKind::Wild) |
+        (_, &PatternKind::Binding { subpattern: None, .. }) => {
+            return witness.clone();
+        }
+        (&PatternKind::Wild, _) => {
+            return escaping_value(cx, pattern).unwrap_or_else(|| witness.clone());
+        }
+        (&PatternKind::Leaf { ref subpatterns },
+         &PatternKind::Leaf { subpatterns: ref pattern_fields }) => {
+            PatternKind::Leaf {
+                subpatterns: concretize_fields(cx, subpatterns, pattern_fields)
+            }
+        }
+        (&PatternKind::Variant { adt_def, substs, variant_index, ref subpatterns },
+         &PatternKind::Variant { variant_index: pattern_index,
+                                 subpatterns: ref pattern_fields, .. })
+            if variant_index == pattern_index => {
+            PatternKind::Variant {
+                adt_def,
+                substs,
+                variant_index,
+                subpatterns: concretize_fields(cx, subpatterns, pattern_fields)
+            }
+        }
+        (&PatternKind::Deref { ref subpattern },
+         &PatternKind::Deref { subpattern: ref pattern_subpattern }) => {
+            PatternKind::Deref {
+                subpattern: concretize_witness(cx, subpattern, pattern_subpattern)
+            }
+        }
+        // The witness has already diverged from `pattern` here.
+        _ => return fill_wildcards(cx, witness),
+    };
+    Pattern { ty: witness.ty, span: witness.span, kind: box kind }
+}
+
+fn concretize_fields<'a, 'tcx>(cx: &MatchCheckCtxt<'a, 'tcx>,
+                               witness_fields: &[FieldPattern<'tcx>],
+                               pattern_fields: &[FieldPattern<'tcx>])
+                               -> Vec<FieldPattern<'tcx>>
+{
+    witness_fields.iter().map(|witness_field| {
+        let pattern = match pattern_fields.iter().find(|f| f.field == witness_field.field) {
+            Some(pattern_field) => {
+                concretize_witness(cx, &witness_field.pattern, &pattern_field.pattern)
+            }
+            // The pattern accepts any value for a field it leaves out.
+            None => witness_field.pattern.clone(),
+        };
+        FieldPattern { field: witness_field.field, pattern }
+    }).collect()
+}
+
+fn fill_wildcards<'a, 'tcx>(cx: &MatchCheckCtxt<'a, 'tcx>,
+                            witness: &Pattern<'tcx>)
+                            -> Pattern<'tcx>
+{
+    struct WildcardFiller<'b, 'a: 'b, 'tcx: 'a>(&'b MatchCheckCtxt<'a, 'tcx>);
+
+    impl<'b, 'a, 'tcx> PatternFolder<'tcx> for WildcardFiller<'b, 'a, 'tcx> {
+        fn fold_pattern(&mut self, pat: &Pattern<'tcx>) -> Pattern<'tcx> {
+            match *pat.kind {
+                PatternKind::Wild => {
+                    arbitrary_value(self.0, pat.ty).unwrap_or_else(|| pat.clone())
+                }
+                _ => pat.super_fold_with(self),
+            }
+        }
+    }
+
+    WildcardFiller(cx).fold_pattern(witness)
+}
+
+fn const_pattern<'a, 'tcx>(cx: &MatchCheckCtxt<'a, 'tcx>,
+                           ty: Ty<'tcx>,
+                           val: ConstVal<'tcx>)
+                           -> Pattern<'tcx>
+{
+    Pattern {
+        ty,
+        span: DUMMY_SP,
+        kind: box PatternKind::Constant { value: cx.tcx.mk_const(ty::Const { val, ty }) },
+    }
+}
+
+/// Some value of type `ty`, preferring zero, `false` and fieldless variants.
+fn arbitrary_value<'a, 'tcx>(cx: &MatchCheckCtxt<'a, 'tcx>, ty: Ty<'tcx>)
+                             -> Option<Pattern<'tcx>>
+{
+    let target = &cx.tcx.sess.target;
+    let kind = match ty.sty {
+        ty::TyBool => return Some(const_pattern(cx, ty, ConstVal::Bool(false))),
+        ty::TyInt(ity) => {
+            let zero = ConstInt::new_signed_truncating(0, ity, target.isize_ty);
+            return Some(const_pattern(cx, ty, ConstVal::Integral(zero)));
+        }
+        ty::TyUint(uty) => {
+            let zero = ConstInt::new_unsigned_truncating(0, uty, target.usize_ty);
+            return Some(const_pattern(cx, ty, ConstVal::Integral(zero)));
+        }
+        ty::TyTuple(ref tys, _) => {
+            PatternKind::Leaf {
+                subpatterns: tys.iter().enumerate().map(|(i, &ty)| {
+                    FieldPattern {
+                        field: Field::new(i),
+                        pattern: arbitrary_value(cx, ty).unwrap_or(Pattern {
+                            ty,
+                            span: DUMMY_SP,
+                            kind: box PatternKind::Wild,
+                        }),
+                    }
+                }).collect()
+            }
+        }
+        ty::TyAdt(adt_def, substs) if adt_def.is_enum() && adt_def.variants.len() > 1 => {
+            let variant_index = match adt_def.variants.iter().position(|v| {
+                v.fields.is_empty() && !cx.is_variant_uninhabited(v, substs)
+            }) {
+                Some(variant_index) => variant_index,
+                None => return None,
+            };
+            PatternKind::Variant { adt_def, substs, variant_index, subpatterns: vec![] }
+        }
+        _ => return None,
+    };
+    Some(Pattern { ty, span: DUMMY_SP, kind: box kind })
+}
+
+/// Some value of the type of `pattern` that `pattern` does not match.
+fn escaping_value<'a, 'tcx>(cx: &MatchCheckCtxt<'a, 'tcx>, pattern: &Pattern<'tcx>)
+                            -> Option<Pattern<'tcx>>
+{
+    let wild = |ty: Ty<'tcx>| Pattern { ty, span: DUMMY_SP, kind: box PatternKind::Wild };
+    let kind = match *pattern.kind {
+        PatternKind::Wild | PatternKind::Binding { subpattern: None, .. } => return None,
+        PatternKind::Binding { subpattern: Some(ref subpattern), .. } => {
+            return escaping_value(cx, subpattern);
+        }
+        PatternKind::Constant { value } => {
+            let val = match value.val {
+                ConstVal::Bool(b) => ConstVal::Bool(!b),
+                ConstVal::Integral(i) => match i - i {
+                    Ok(zero) => ConstVal::Integral(if zero != i { zero } else { zero.wrap_incr() }),
+                    Err(_) => return None,
+                },
+                _ => return None,
+            };
+            return Some(const_pattern(cx, pattern.ty, val));
+        }
+        PatternKind::Range { lo, hi, end } => {
+            let (lo, hi) = match (&lo.val, &hi.val) {
+                (&ConstVal::Integral(lo), &ConstVal::Integral(hi)) => (lo, hi),
+                _ => return None,
+            };
+            let zero = match lo - lo {
+                Ok(zero) => zero,
+                Err(_) => return None,
+            };
+            let in_range = |v: ConstInt| lo <= v && match end {
+                RangeEnd::Included => v <= hi,
+                RangeEnd::Excluded => v < hi,
+            };
+            // Zero reads best; otherwise step just past either end, taking
+            // care that the step doesn't wrap back into the range.
+            let candidates = [Some(zero), Some(hi), Some(hi.wrap_incr()),
+                              (lo - zero.wrap_incr()).ok()];
+            return candidates.iter().filter_map(|&v| v).find(|&v| !in_range(v)).map(|val| {
+                const_pattern(cx, pattern.ty, ConstVal::Integral(val))
+            });
+        }
+        PatternKind::Variant { adt_def, substs, variant_index, .. } => {
+            let other_variants = || adt_def.variants.iter().enumerate().filter(|&(i, v)| {
+                i != variant_index && !cx.is_variant_uninhabited(v, substs)
+            });
+            let (variant_index, variant) =
+                match other_variants().find(|&(_, v)| v.fields.is_empty())
+                                      .or_else(|| other_variants().next()) {
+                    Some(other) => other,
+                    None => return None,
+                };
+            PatternKind::Variant {
+                adt_def,
+                substs,
+                variant_index,
+                subpatterns: variant.fields.iter().enumerate().map(|(i, field)| {
+                    let ty = field.ty(cx.tcx, substs);
+                    FieldPattern {
+                        field: Field::new(i),
+                        pattern: arbitrary_value(cx, ty).unwrap_or_else(|| wild(ty)),
+                    }
+                }).collect()
+            }
+        }
+        PatternKind::Leaf { ref subpatterns } => {
+            let tys = constructor_sub_pattern_tys(cx, &Single, pattern.ty);
+            let mut escaped = false;
+            let subpatterns: Vec<_> = tys.into_iter().enumerate().map(|(i, ty)| {
+                let field = Field::new(i);
+                let escaping = subpatterns.iter()
+                    .find(|f| f.field == field)
+                    .and_then(|f| escaping_value(cx, &f.pattern));
+                escaped |= escaping.is_some();
+                FieldPattern {
+                    field,
+                    pattern: escaping.or_else(|| arbitrary_value(cx, ty))
+                                     .unwrap_or_else(|| wild(ty)),
+                }
+            }).collect();
+            if !escaped {
+                return None;
+            }
+            PatternKind::Leaf { subpatterns }
+        }
+        PatternKind::Deref { ref subpattern } => {
+            match escaping_value(cx, subpattern) {
+                Some(subpattern) => PatternKind::Deref { subpattern },
+                None => return None,
+            }
+        }
+        PatternKind::Slice { .. } | PatternKind::Array { .. } => return None,
+    };
+    Some(Pattern { ty: pattern.ty, span: DUMMY_SP, kind: box kind })
+}
+
 /// This determines the set of all possible constructors of a pattern matching
 /// values of type `left_ty`. For vectors, this would normally be an infinite set
 /// but is instead bounded by the maximum fixed length of slice patterns in
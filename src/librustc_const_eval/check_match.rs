@@ -8,16 +8,18 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use _match::{MatchCheckCtxt, Matrix, expand_pattern, is_useful};
+use _match::{MatchCheckCtxt, Matrix, concretize_witness, expand_pattern, is_useful};
 use _match::Usefulness::*;
 use _match::WitnessPreference::*;
 
 use pattern::{Pattern, PatternContext, PatternError, PatternKind};
+use pattern::{PatternFoldable, PatternFolder};
 
 use rustc::middle::expr_use_visitor::{ConsumeMode, Delegate, ExprUseVisitor};
 use rustc::middle::expr_use_visitor::{LoanCause, MutateMode};
 use rustc::middle::expr_use_visitor as euv;
 use rustc::middle::mem_categorization::{cmt};
+use rustc::middle::const_val::ConstVal;
 use rustc::middle::region;
 use rustc::session::Session;
 use rustc::ty::{self, Ty, TyCtxt};
@@ -26,8 +28,9 @@ use rustc::lint;
 use rustc_errors::DiagnosticBuilder;
 
 use rustc::hir::def::*;
+use rustc::hir::def_id::CrateNum;
 use rustc::hir::intravisit::{self, Visitor, FnKind, NestedVisitorMap};
-use rustc::hir::{self, Pat, PatKind};
+use rustc::hir::{self, Pat, PatKind, RangeEnd};
 
 use rustc_back::slice;
 
@@ -94,10 +97,16 @@ impl<'a, 'tcx> Visitor<'tcx> for MatchVisitor<'a, 'tcx> {
     fn visit_local(&mut self, loc: &'tcx hir::Local) {
         intravisit::walk_local(self, loc);
 
-        self.check_irrefutable(&loc.pat, match loc.source {
-            hir::LocalSource::Normal => "local binding",
-            hir::LocalSource::ForLoopDesugar => "`for` loop binding",
-        });
+        let (msg, help) = match loc.source {
+            // `if let` has no place for a type annotation and needs an
+            // expression to match against, so only suggest it for `let PAT = EXPR`.
+            hir::LocalSource::Normal if loc.ty.is_none() && loc.init.is_some() => {
+                ("local binding", RefutableHelp::IfLet(loc.span))
+            }
+            hir::LocalSource::Normal => ("local binding", RefutableHelp::Nothing),
+            hir::LocalSource::ForLoopDesugar => ("`for` loop binding", RefutableHelp::ForLoop),
+        };
+        self.check_irrefutable(&loc.pat, msg, help);
 
         // Check legality of move bindings and `@` patterns.
         self.check_patterns(false, slice::ref_slice(&loc.pat));
@@ -107,7 +116,7 @@ impl<'a, 'tcx> Visitor<'tcx> for MatchVisitor<'a, 'tcx> {
         intravisit::walk_body(self, body);
 
         for arg in &body.arguments {
-            self.check_irrefutable(&arg.pat, "function argument");
+            self.check_irrefutable(&arg.pat, "function argument", RefutableHelp::MatchArgument);
             self.check_patterns(false, slice::ref_slice(&arg.pat));
         }
     }
@@ -183,8 +192,15 @@ impl<'a, 'tcx> MatchVisitor<'a, 'tcx> {
                 return;
             }
 
-            // Fourth, check for unreachable arms.
-            check_arms(cx, &inlined_arms, source);
+            // Fourth, check for unreachable arms. The fallback arm of a
+            // match linted as irrefutable is unreachable by construction.
+            let checked_arms = if source == hir::MatchSource::Normal &&
+                                  check_irrefutable_match(cx, scrut, arms, &inlined_arms) {
+                &inlined_arms[..1]
+            } else {
+                &inlined_arms[..]
+            };
+            check_arms(cx, checked_arms, source);
 
             // Then, if the match has no arms, check whether the scrutinee
             // is uninhabited.
@@ -231,20 +247,18 @@ impl<'a, 'tcx> MatchVisitor<'a, 'tcx> {
         }
     }
 
-    fn check_irrefutable(&self, pat: &'tcx Pat, origin: &str) {
+    fn check_irrefutable(&self, pat: &'tcx Pat, origin: &str, help: RefutableHelp) {
         let module = self.tcx.hir.get_module_parent(pat.id);
         MatchCheckCtxt::create_and_enter(self.tcx, module, |ref mut cx| {
             let mut patcx = PatternContext::new(self.tcx,
                                                 self.param_env.and(self.identity_substs),
                                                 self.tables);
             let pattern = patcx.lower_pattern(pat);
-            let pattern_ty = pattern.ty;
-            let pats : Matrix = vec![vec![
-                expand_pattern(cx, pattern)
-            ]].into_iter().collect();
+            let pattern = expand_pattern(cx, FullRangeExpander.fold_pattern(&pattern));
+            let pats : Matrix = vec![vec![pattern]].into_iter().collect();
 
             let wild_pattern = Pattern {
-                ty: pattern_ty,
+                ty: pattern.ty,
                 span: DUMMY_SP,
                 kind: box PatternKind::Wild,
             };
@@ -254,18 +268,204 @@ impl<'a, 'tcx> MatchVisitor<'a, 'tcx> {
                 Useful => bug!()
             };
 
-            let pattern_string = witness[0].single_pattern().to_string();
-            let mut diag = struct_span_err!(
-                self.tcx.sess, pat.span, E0005,
-                "refutable pattern in {}: `{}` not covered",
-                origin, pattern_string
-            );
+            let pattern_string = concretize_witness(cx, witness[0].single_pattern(), pattern)
+                .to_string();
+            let mut diag = match help {
+                RefutableHelp::MatchArgument => struct_span_err!(
+                    self.tcx.sess, pat.span, E0641,
+                    "refutable pattern in {}: `{}` not covered",
+                    origin, pattern_string
+                ),
+                _ => struct_span_err!(
+                    self.tcx.sess, pat.span, E0005,
+                    "refutable pattern in {}: `{}` not covered",
+                    origin, pattern_string
+                ),
+            };
             diag.span_label(pat.span, format!("pattern `{}` not covered", pattern_string));
+            label_refutable_sub_patterns(self.tcx, pattern, &mut diag);
+            if let Some(note) = uncovered_variants_note(cx, pattern) {
+                diag.note(&note);
+            }
+            match help {
+                RefutableHelp::Nothing => {}
+                RefutableHelp::IfLet(sp) => {
+                    if let Ok(snippet) = self.tcx.sess.codemap().span_to_snippet(sp) {
+                        diag.help(&format!("to run code only when the pattern matches, move \
+                                            it into the body of `if {} {{ ... }}`",
+                                           snippet));
+                    }
+                }
+                RefutableHelp::ForLoop => {
+                    diag.help("`for` loop bindings must be irrefutable; bind each item to a \
+                               fresh name and match on it in the loop body, or skip the items \
+                               that don't match with `filter_map`");
+                }
+                RefutableHelp::MatchArgument => {
+                    let pat_str = hir::print::to_string(hir::print::NO_ANN,
+                                                        |s| s.print_pat(pat));
+                    diag.span_help(pat.span, &format!(
+                        "function arguments must be irrefutable; bind the argument to a \
+                         fresh name and match on it in the body instead:\n\n    \
+                         arg: {}\n\n    \
+                         match arg {{\n        \
+                             {} => {{ /* ... */ }}\n        \
+                             _ => unreachable!(),\n    \
+                         }}",
+                        pattern.ty, pat_str));
+                }
+            }
             diag.emit();
         });
     }
 }
 
+/// The fix-up offered alongside a refutable pattern error.
+enum RefutableHelp {
+    Nothing,
+    /// Move the code following the `let` statement at this span into the
+    /// body of an `if let`.
+    IfLet(Span),
+    /// Move the pattern of a function argument into a `match` in the body.
+    MatchArgument,
+    /// Match on the items of a `for` loop in its body, or filter them.
+    ForLoop,
+}
+
+/// Points at each constant, range and enum variant within `pattern` that
+/// can fail to match, explaining why.
+fn label_refutable_sub_patterns<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                          pattern: &Pattern<'tcx>,
+                                          diag: &mut DiagnosticBuilder) {
+    match *pattern.kind {
+        PatternKind::Wild |
+        PatternKind::Binding { subpattern: None, .. } => {}
+        PatternKind::Binding { subpattern: Some(ref subpattern), .. } |
+        PatternKind::Deref { ref subpattern } => {
+            label_refutable_sub_patterns(tcx, subpattern, diag);
+        }
+        PatternKind::Constant { .. } => {
+            diag.span_label(pattern.span, "this literal does not match all values");
+        }
+        PatternKind::Range { .. } => {
+            // Ranges covering every value of their type were replaced by
+            // wildcards in `FullRangeExpander`, so this one is refutable.
+            diag.span_label(pattern.span, "this range does not cover all values");
+            if let (Ok(snippet), Some((min, max))) =
+                (tcx.sess.codemap().span_to_snippet(pattern.span), int_type_bounds(pattern.ty))
+            {
+                diag.note(&format!("`{}` ranges from `{}` to `{}`, but values outside of `{}` \
+                                    are not matched",
+                                   pattern.ty, min, max, snippet));
+            }
+        }
+        PatternKind::Variant { ref subpatterns, .. } |
+        PatternKind::Leaf { ref subpatterns } => {
+            if let PatternKind::Variant { .. } = *pattern.kind {
+                diag.span_label(pattern.span, "this variant does not match all values");
+            }
+            for field in subpatterns {
+                label_refutable_sub_patterns(tcx, &field.pattern, diag);
+            }
+        }
+        PatternKind::Slice { ref prefix, ref slice, ref suffix } |
+        PatternKind::Array { ref prefix, ref slice, ref suffix } => {
+            for subpattern in prefix.iter().chain(slice).chain(suffix) {
+                label_refutable_sub_patterns(tcx, subpattern, diag);
+            }
+        }
+    }
+}
+
+/// If `pattern` matches a single variant of an enum, names the enum's other
+/// variants, which a `match` would have to handle as well.
+fn uncovered_variants_note<'a, 'tcx>(cx: &MatchCheckCtxt<'a, 'tcx>,
+                                     pattern: &Pattern<'tcx>)
+                                     -> Option<String> {
+    // Listing every variant of a large enum would bury the error.
+    const LIMIT: usize = 3;
+
+    let mut pattern = pattern;
+    while let PatternKind::Binding { subpattern: Some(ref subpattern), .. } = *pattern.kind {
+        pattern = subpattern;
+    }
+    let (adt_def, substs, variant_index) = match *pattern.kind {
+        PatternKind::Variant { adt_def, substs, variant_index, .. } => {
+            (adt_def, substs, variant_index)
+        }
+        _ => return None,
+    };
+
+    let names: Vec<_> = adt_def.variants.iter().enumerate()
+        .filter(|&(i, variant)| i != variant_index && !cx.is_variant_uninhabited(variant, substs))
+        .map(|(_, variant)| format!("`{}`", variant.name))
+        .collect();
+    let list = match names.len() {
+        0 => return None,
+        1 => names[0].clone(),
+        n if n <= LIMIT => format!("{} and {}", names[..n - 1].join(", "), names[n - 1]),
+        n => {
+            let others = n - LIMIT;
+            format!("{} and {} other{}", names[..LIMIT].join(", "), others,
+                    if others == 1 { "" } else { "s" })
+        }
+    };
+    let (noun, verb, pronoun) = if names.len() == 1 {
+        ("variant", "is", "it")
+    } else {
+        ("variants", "are", "them")
+    };
+    Some(format!("{} {} of `{}` {} not covered; use a `match` to handle {}",
+                 noun, list, pattern.ty, verb, pronoun))
+}
+
+/// Returns the smallest and largest values of an integer type, for display.
+/// The bounds of `isize` and `usize` depend on the target, so they are
+/// given symbolically.
+fn int_type_bounds(ty: Ty) -> Option<(String, String)> {
+    macro_rules! bounds {
+        ($t:ident) => { ($t::min_value().to_string(), $t::max_value().to_string()) }
+    }
+    Some(match ty.sty {
+        ty::TyInt(ast::IntTy::I8) => bounds!(i8),
+        ty::TyInt(ast::IntTy::I16) => bounds!(i16),
+        ty::TyInt(ast::IntTy::I32) => bounds!(i32),
+        ty::TyInt(ast::IntTy::I64) => bounds!(i64),
+        ty::TyInt(ast::IntTy::I128) => bounds!(i128),
+        ty::TyInt(ast::IntTy::Is) => (format!("isize::MIN"), format!("isize::MAX")),
+        ty::TyUint(ast::UintTy::U8) => bounds!(u8),
+        ty::TyUint(ast::UintTy::U16) => bounds!(u16),
+        ty::TyUint(ast::UintTy::U32) => bounds!(u32),
+        ty::TyUint(ast::UintTy::U64) => bounds!(u64),
+        ty::TyUint(ast::UintTy::U128) => bounds!(u128),
+        ty::TyUint(ast::UintTy::Us) => (format!("usize::MIN"), format!("usize::MAX")),
+        _ => return None,
+    })
+}
+
+/// Replaces range patterns that cover every value of their integer type,
+/// such as `0...255u8`, with wildcards, so that they are considered
+/// irrefutable.
+struct FullRangeExpander;
+impl<'tcx> PatternFolder<'tcx> for FullRangeExpander {
+    fn fold_pattern(&mut self, pat: &Pattern<'tcx>) -> Pattern<'tcx> {
+        if let PatternKind::Range { lo, hi, end: RangeEnd::Included } = *pat.kind {
+            if let (&ConstVal::Integral(lo), &ConstVal::Integral(hi)) = (&lo.val, &hi.val) {
+                // `hi + 1` can only wrap around to `lo` if `hi` is the
+                // largest value of the type and `lo` the smallest.
+                if lo <= hi && hi.wrap_incr() == lo {
+                    return Pattern {
+                        ty: pat.ty,
+                        span: pat.span,
+                        kind: box PatternKind::Wild,
+                    };
+                }
+            }
+        }
+        pat.super_fold_with(self)
+    }
+}
+
 fn check_for_bindings_named_the_same_as_variants(cx: &MatchVisitor, pat: &Pat) {
     pat.walk(|p| {
         if let PatKind::Binding(_, _, name, None) = p.node {
@@ -313,6 +513,79 @@ fn pat_is_catchall(pat: &Pat) -> bool {
     }
 }
 
+/// Lints `match x { PAT => ..., _ => unreachable!() }` where `PAT` matches
+/// every value of the scrutinee's type, as `let PAT = x;` says the same.
+/// Returns whether the lint was emitted; if it is allowed, the fallback
+/// arm is left to `unreachable_patterns` instead.
+fn check_irrefutable_match<'a, 'tcx>(cx: &mut MatchCheckCtxt<'a, 'tcx>,
+                                     scrut: &hir::Expr,
+                                     arms: &[hir::Arm],
+                                     inlined_arms: &[(Vec<(&'a Pattern<'tcx>, &hir::Pat)>,
+                                                      Option<&hir::Expr>)])
+                                     -> bool
+{
+    if arms.len() != 2 || arms.iter().any(|arm| arm.pats.len() != 1 || arm.guard.is_some()) {
+        return false;
+    }
+    let fallback = &arms[1];
+    match fallback.pats[0].node {
+        PatKind::Wild => {}
+        _ => return false,
+    }
+    if !is_std_unreachable(cx.tcx, fallback.body.span) {
+        return false;
+    }
+
+    let (pattern, hir_pat) = inlined_arms[0].0[0];
+    let (level, _) = cx.tcx.lint_level_at_node(lint::builtin::IRREFUTABLE_MATCH, hir_pat.id);
+    if level == lint::Allow {
+        return false;
+    }
+    let seen: Matrix = vec![vec![pattern]].into_iter().collect();
+    let wild_pattern = Pattern {
+        ty: pattern.ty,
+        span: DUMMY_SP,
+        kind: box PatternKind::Wild,
+    };
+    match is_useful(cx, &seen, &[&wild_pattern], LeaveOutWitness) {
+        NotUseful => {}
+        Useful | UsefulWithWitness(_) => return false,
+    }
+
+    let mut err = cx.tcx.struct_span_lint_node(
+        lint::builtin::IRREFUTABLE_MATCH,
+        hir_pat.id,
+        hir_pat.span,
+        "irrefutable pattern in `match` with an unreachable fallback arm",
+    );
+    err.span_label(hir_pat.span,
+                   format!("this pattern matches every value of type `{}`", pattern.ty));
+    err.span_label(fallback.pats[0].span, "so this arm is never reached");
+    let codemap = cx.tcx.sess.codemap();
+    if let (Ok(pat_snippet), Ok(scrut_snippet)) = (codemap.span_to_snippet(hir_pat.span),
+                                                    codemap.span_to_snippet(scrut.span)) {
+        err.help(&format!("use `let {} = {};` instead", pat_snippet, scrut_snippet));
+    }
+    err.emit();
+    true
+}
+
+/// Whether `span` was expanded from the standard library's
+/// `unreachable!()`, rather than from a macro of the same name.
+fn is_std_unreachable<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, span: Span) -> bool {
+    let callee = match span.source_callee() {
+        Some(callee) => callee,
+        None => return false,
+    };
+    let def_site = match callee.span {
+        Some(def_site) if callee.name() == "unreachable" => def_site,
+        _ => return false,
+    };
+    let file = tcx.sess.codemap().lookup_char_pos(def_site.lo()).file;
+    let krate = tcx.crate_name(CrateNum::from_u32(file.crate_of_origin));
+    krate == "std" || krate == "core"
+}
+
 // Check for unreachable patterns
 fn check_arms<'a, 'tcx>(cx: &mut MatchCheckCtxt<'a, 'tcx>,
                         arms: &[(Vec<(&'a Pattern<'tcx>, &hir::Pat)>, Option<&hir::Expr>)],
@@ -180,6 +180,8 @@ if let Some(y) = x {
     // do something
 }
 ```
+
+Function arguments are subject to the same restriction; see E0641.
 "##,
 
 E0007: r##"
@@ -561,6 +563,50 @@ The `op_string_ref` binding has type `&Option<&String>` in both cases.
 See also https://github.com/rust-lang/rust/issues/14587
 "##,
 
+E0641: r##"
+A function or closure argument was bound with a refutable pattern.
+
+Erroneous code example:
+
+```compile_fail,E0641
+fn first_of_some((Some(x), _): (Option<u8>, u8)) -> u8 {
+    // error: refutable pattern in function argument: `(None, 0u8)` not covered
+    x
+}
+```
+
+Like `let` bindings (see E0005), function arguments can only be bound with
+irrefutable patterns: patterns that match every possible value of the
+argument's type, such as bindings, wildcards, tuples and structs of those, and
+single-variant enums whose fields are irrefutable. The caller can pass any
+value of the argument type, so there is nothing to fall back on when the
+pattern fails to match.
+
+Instead, bind the argument to a name and match on it in the body of the
+function, handling the cases the pattern did not cover:
+
+```
+fn first_of_some(arg: (Option<u8>, u8)) -> u8 {
+    match arg {
+        (Some(x), _) => x,
+        (None, y) => y,
+    }
+}
+```
+
+If the remaining cases cannot happen, `if let` or a `match` whose other arm
+calls `unreachable!()` works as well:
+
+```
+fn first_of_some(arg: (Option<u8>, u8)) -> u8 {
+    if let (Some(x), _) = arg {
+        return x;
+    }
+    unreachable!()
+}
+```
+"##,
+
 }
 
 
@@ -61,6 +61,15 @@ impl<T: Idx> fmt::Debug for IdxSet<T> {
 }
 
 impl<T: Idx> IdxSetBuf<T> {
+    /// Returns the number of words needed to hold a set over the range
+    /// 0..universe_size, or `None` if that can't be computed without
+    /// overflowing. Callers that can't rule that out should check it
+    /// before creating a set.
+    pub fn num_words(universe_size: usize) -> Option<usize> {
+        let bits_per_word = mem::size_of::<Word>() * 8;
+        universe_size.checked_add(bits_per_word - 1).map(|bits| bits / bits_per_word)
+    }
+
     fn new(init: Word, universe_size: usize) -> Self {
         let bits_per_word = mem::size_of::<Word>() * 8;
         let num_words = (universe_size + (bits_per_word - 1)) / bits_per_word;
@@ -269,3 +278,13 @@ impl<'a, T: Idx> Iterator for Iter<'a, T> {
         }
     }
 }
+
+#[test]
+fn num_words_checks_overflow() {
+    let bits_per_word = mem::size_of::<Word>() * 8;
+    assert_eq!(IdxSetBuf::<usize>::num_words(0), Some(0));
+    assert_eq!(IdxSetBuf::<usize>::num_words(1), Some(1));
+    assert_eq!(IdxSetBuf::<usize>::num_words(bits_per_word), Some(1));
+    assert_eq!(IdxSetBuf::<usize>::num_words(bits_per_word + 1), Some(2));
+    assert_eq!(IdxSetBuf::<usize>::num_words(usize::max_value()), None);
+}
@@ -266,6 +266,14 @@ pub fn compile_input(sess: &Session,
         sess.code_stats.borrow().print_type_sizes();
     }
 
+    if sess.opts.debugging_opts.coercion_stats {
+        sess.print_coercion_stats();
+    }
+
+    if sess.opts.debugging_opts.dump_coercions {
+        sess.print_coercion_sites();
+    }
+
     let (phase5_result, trans) =
         phase_5_run_llvm_passes::<DefaultTransCrate>(sess, &dep_graph, trans);
 
@@ -145,6 +145,32 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
                 let source = unpack!(block = this.as_operand(block, scope, source));
                 block.and(Rvalue::Cast(CastKind::Unsize, source, expr.ty))
             }
+            ExprKind::BorrowArrayElements { source } => {
+                // Evaluate the array reference once, then borrow each
+                // element through it.
+                let source = unpack!(block = this.as_temp(block, scope, source));
+                let elem_tys = match expr.ty.sty {
+                    ty::TyTuple(tys, _) => tys,
+                    _ => span_bug!(expr_span, "borrowing array elements into {:?}", expr.ty),
+                };
+                let min_length = elem_tys.len() as u32;
+                let fields = elem_tys.iter().enumerate().map(|(i, &ref_ty)| {
+                    let region = match ref_ty.sty {
+                        ty::TyRef(region, _) => region,
+                        _ => span_bug!(expr_span, "borrowing array element into {:?}", ref_ty),
+                    };
+                    let elem = Lvalue::Local(source).deref().elem(ProjectionElem::ConstantIndex {
+                        offset: i as u32,
+                        min_length,
+                        from_end: false,
+                    });
+                    let temp = this.temp(ref_ty, expr_span);
+                    this.cfg.push_assign(block, source_info, &temp,
+                                         Rvalue::Ref(region, BorrowKind::Shared, elem));
+                    Operand::Consume(temp)
+                }).collect();
+                block.and(Rvalue::Aggregate(box AggregateKind::Tuple, fields))
+            }
             ExprKind::Array { fields } => {
                 // (*) We would (maybe) be closer to trans if we
                 // handled this and other aggregate cases via
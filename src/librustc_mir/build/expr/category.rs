@@ -73,6 +73,7 @@ impl Category {
             ExprKind::ClosureFnPointer { .. } |
             ExprKind::UnsafeFnPointer { .. } |
             ExprKind::Unsize { .. } |
+            ExprKind::BorrowArrayElements { .. } |
             ExprKind::Repeat { .. } |
             ExprKind::Borrow { .. } |
             ExprKind::Assign { .. } |
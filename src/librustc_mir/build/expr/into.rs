@@ -292,6 +292,7 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
             ExprKind::ClosureFnPointer { .. } |
             ExprKind::UnsafeFnPointer { .. } |
             ExprKind::Unsize { .. } |
+            ExprKind::BorrowArrayElements { .. } |
             ExprKind::Repeat { .. } |
             ExprKind::Borrow { .. } |
             ExprKind::VarRef { .. } |
@@ -16,11 +16,15 @@ use hair::cx::block;
 use hair::cx::to_ref::ToRef;
 use rustc::hir::def::{Def, CtorKind};
 use rustc::middle::const_val::ConstVal;
+use rustc::middle::lang_items::BoxIntoRawFnLangItem;
 use rustc::ty::{self, AdtKind, VariantDef, Ty};
 use rustc::ty::adjustment::{Adjustment, Adjust, AutoBorrow};
+use rustc::ty::subst::Kind;
 use rustc::ty::cast::CastKind as TyCastKind;
 use rustc::hir;
 
+use std::iter;
+
 impl<'tcx> Mirror<'tcx> for &'tcx hir::Expr {
     type Output = Expr<'tcx>;
 
@@ -91,9 +95,24 @@ fn apply_adjustment<'a, 'gcx, 'tcx>(cx: &mut Cx<'a, 'gcx, 'tcx>,
         Adjust::NeverToAny => {
             ExprKind::NeverToAny { source: expr.to_ref() }
         }
-        Adjust::MutToConstPointer => {
+        Adjust::MutToConstPointer |
+        Adjust::ArrayToPointer |
+        Adjust::ReinterpretSlice => {
             ExprKind::Cast { source: expr.to_ref() }
         }
+        Adjust::Box => {
+            ExprKind::Box { value: expr.to_ref() }
+        }
+        Adjust::BoxIntoRaw => {
+            let into_raw = cx.tcx.require_lang_item(BoxIntoRawFnLangItem);
+            let substs = cx.tcx.mk_substs(iter::once(Kind::from(expr.ty.boxed_ty())));
+            let fun = method_callee(cx, hir_expr, Some((into_raw, substs)));
+            ExprKind::Call {
+                ty: fun.ty,
+                fun: fun.to_ref(),
+                args: vec![expr.to_ref()],
+            }
+        }
         Adjust::Deref(None) => {
             ExprKind::Deref { arg: expr.to_ref() }
         }
@@ -149,6 +168,26 @@ fn apply_adjustment<'a, 'gcx, 'tcx>(cx: &mut Cx<'a, 'gcx, 'tcx>,
         Adjust::Unsize => {
             ExprKind::Unsize { source: expr.to_ref() }
         }
+        Adjust::BorrowArrayElements => {
+            ExprKind::BorrowArrayElements { source: expr.to_ref() }
+        }
+        Adjust::WrapUnit => {
+            match adjustment.target.sty {
+                ty::TyAdt(adt_def, substs) => {
+                    ExprKind::Adt {
+                        adt_def,
+                        variant_index: 0,
+                        substs,
+                        fields: vec![FieldExprRef {
+                            name: Field::new(0),
+                            expr: expr.to_ref(),
+                        }],
+                        base: None,
+                    }
+                }
+                ref sty => span_bug!(span, "unexpected target type for WrapUnit: {:?}", sty),
+            }
+        }
     };
 
     Expr {
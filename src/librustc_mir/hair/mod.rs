@@ -188,6 +188,9 @@ pub enum ExprKind<'tcx> {
     Unsize {
         source: ExprRef<'tcx>,
     },
+    BorrowArrayElements {
+        source: ExprRef<'tcx>,
+    },
     If {
         condition: ExprRef<'tcx>,
         then: ExprRef<'tcx>,
@@ -0,0 +1,81 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! With `-Z verify-drops`, checks that no local is read after a `Drop`
+//! of it. Once a local has been dropped, the next thing to happen to it
+//! must be a new assignment or the end of its storage, both of which end
+//! liveness. So a dropped local that is still live for a regular use where
+//! the drop continues is read again, which the MIR builder should never
+//! produce.
+//!
+//! `DropAndReplace` writes a new value right away and is not checked.
+
+use rustc::mir::{BasicBlock, Local, Location, Lvalue, Mir, TerminatorKind};
+use rustc_data_structures::indexed_vec::IndexVec;
+use util::liveness::{LivenessResult, LivenessResults};
+
+pub fn verify_no_use_after_drop<'tcx>(mir: &Mir<'tcx>, liveness: &LivenessResults) {
+    for (block, data) in mir.basic_blocks().iter_enumerated() {
+        let terminator = data.terminator();
+        let (local, targets) = match terminator.kind {
+            TerminatorKind::Drop { location: Lvalue::Local(local), target, unwind } => {
+                (local, Some(target).into_iter().chain(unwind))
+            }
+            _ => continue,
+        };
+
+        for target in targets {
+            if liveness.regular.ins[target].contains(&local) {
+                let drop_location = Location { block, statement_index: data.statements.len() };
+                let use_location = first_use(mir, &liveness.regular, local, target);
+                span_bug!(terminator.source_info.span,
+                          "{:?} is used at {:?} after it is dropped at {:?}",
+                          local, use_location, drop_location);
+            }
+        }
+    }
+}
+
+/// Finds a location reached from the start of `start` that uses
+/// `local`, which must be live on entry to `start`.
+///
+/// A local that is live on entry to a block is either used in it before
+/// being defined, or live on entry to one of its successors, so following
+/// the blocks it is live on entry to always ends at a use.
+fn first_use<'tcx>(mir: &Mir<'tcx>,
+                   result: &LivenessResult,
+                   local: Local,
+                   start: BasicBlock)
+                   -> Location {
+    let mut visited = IndexVec::from_elem_n(false, mir.basic_blocks().len());
+    let mut queue = vec![start];
+    visited[start] = true;
+    let mut index = 0;
+    while index < queue.len() {
+        let block = queue[index];
+        index += 1;
+
+        let data = &mir[block];
+        for statement_index in 0..data.statements.len() + 1 {
+            let location = Location { block, statement_index };
+            if result.uses_at(mir, location).contains(&local) {
+                return location;
+            }
+        }
+
+        for &successor in data.terminator().successors().iter() {
+            if !visited[successor] && result.ins[successor].contains(&local) {
+                visited[successor] = true;
+                queue.push(successor);
+            }
+        }
+    }
+    bug!("{:?} is live on entry to {:?} but never used", local, start)
+}
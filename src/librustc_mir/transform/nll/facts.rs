@@ -0,0 +1,85 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Writes the liveness results out as input facts for external
+//! experiments with the region analysis, one file per relation:
+//!
+//! - `var_live_at.facts` holds `(local, point)` for every point at which
+//!   the local is live because of a regular use;
+//! - `var_drop_live_at.facts` does the same for locals live because they
+//!   may still be dropped.
+//!
+//! Both are written exactly as the liveness analysis computed them, so a
+//! local that is used and later dropped appears in both relations at the
+//! points where both apply.
+//!
+//! Each line is a tab-separated tuple. Locals are written as `_N` and
+//! points as the `Debug` form of their `Location`, such as `bb0[2]`, so
+//! the ids are stable across runs and match the MIR dumps. The files for
+//! a function go into `<nll-facts-dir>/node<id>`, named like the dumps.
+
+use rustc::mir::{BasicBlock, Local, Location, Mir};
+use rustc::mir::transform::MirSource;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use util::liveness::{LivenessResult, LivenessResults};
+
+pub fn write_liveness_facts<'tcx>(dir: &str,
+                                  source: MirSource,
+                                  mir: &Mir<'tcx>,
+                                  liveness: &LivenessResults)
+                                  -> io::Result<()> {
+    let promotion_id = match source {
+        MirSource::Promoted(_, id) => format!("-{:?}", id),
+        MirSource::GeneratorDrop(_) => format!("-drop"),
+        _ => String::new()
+    };
+
+    let mut path = PathBuf::from(dir);
+    path.push(format!("node{}{}", source.item_id(), promotion_id));
+    fs::create_dir_all(&path)?;
+
+    write_relation(&mut File::create(path.join("var_live_at.facts"))?,
+                   mir, &liveness.regular)?;
+    write_relation(&mut File::create(path.join("var_drop_live_at.facts"))?,
+                   mir, &liveness.drop)?;
+    Ok(())
+}
+
+/// Writes one `(local, point)` line for each local live at each point,
+/// in block order and then statement order.
+fn write_relation<'tcx>(out: &mut Write,
+                        mir: &Mir<'tcx>,
+                        result: &LivenessResult)
+                        -> io::Result<()> {
+    for bb in mir.basic_blocks().indices() {
+        for (location, live) in block_points(mir, result, bb) {
+            for local in live {
+                writeln!(out, "{:?}\t{:?}", local, location)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The locals live at each point of `bb`, in forward order.
+/// `simulate_block` walks the block backwards, so this reverses it.
+fn block_points<'tcx>(mir: &Mir<'tcx>,
+                      result: &LivenessResult,
+                      bb: BasicBlock)
+                      -> Vec<(Location, Vec<Local>)> {
+    let mut points = vec![];
+    result.simulate_block(mir, bb, |location, bits| {
+        points.push((location, bits.iter().collect()));
+    });
+    points.reverse();
+    points
+}
@@ -0,0 +1,33 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Checks the worklist liveness fixpoint against the round-robin one it
+//! replaced (`-Z verify-liveness`). The worklist only revisits a block
+//! when the entry set of a successor changes, so a missed predecessor,
+//! e.g. along a loop back edge, would leave a stale set behind.
+
+use rustc::mir::{BasicBlock, Mir};
+use util::liveness::{self, LivenessResult, LivenessResults};
+
+pub fn verify_liveness<'tcx>(mir: &Mir<'tcx>, liveness: &LivenessResults) {
+    for result in &[&liveness.regular, &liveness.drop] {
+        let expected = liveness::round_robin_liveness_of_locals(mir, result.mode);
+        if let Some(block) = first_difference(result, &expected) {
+            span_bug!(mir.span,
+                      "liveness ({:?}) on entry to or exit from {:?} differs from the \
+                       round-robin fixpoint",
+                      result.mode, block);
+        }
+    }
+}
+
+fn first_difference(a: &LivenessResult, b: &LivenessResult) -> Option<BasicBlock> {
+    a.ins.indices().find(|&block| a.ins[block] != b.ins[block] || a.outs[block] != b.outs[block])
+}
@@ -0,0 +1,181 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Lints calls to `mem::forget` after which the local the user meant to
+//! forget may still be dropped, such as `mem::forget(&x)`, which forgets
+//! a reference and leaves `x` to be dropped as usual.
+//!
+//! The MIR checked here comes before drop elaboration, so a local that was
+//! moved into `mem::forget` still has its drop at the end of its scope and
+//! is drop-live there. Such a drop is only reported if the local may still
+//! be initialized when it is reached.
+//!
+//! Types such as `ManuallyDrop<T>` never need drop, so forgetting them
+//! is never reported.
+
+use rustc::hir::def_id::DefId;
+use rustc::lint::builtin::FORGET_WHILE_DROP_LIVE;
+use rustc::mir::{BasicBlock, BasicBlockData, ClearOnDecode, Local, Lvalue, Mir, Operand};
+use rustc::mir::{Rvalue, StatementKind, TerminatorKind};
+use rustc::mir::transform::MirSource;
+use rustc::ty::{self, TyCtxt};
+use rustc_data_structures::indexed_set::IdxSetBuf;
+use syntax::ast::NodeId;
+use syntax_pos::Span;
+use util::liveness::LivenessResults;
+use super::init::Initialized;
+
+pub fn check_forgotten_drop_live<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                           source: MirSource,
+                                           mir: &Mir<'tcx>,
+                                           liveness: &LivenessResults) {
+    let forget_fn = match tcx.lang_items().forget_fn() {
+        Some(forget_fn) => forget_fn,
+        None => return,
+    };
+    let param_env = tcx.param_env(tcx.hir.local_def_id(source.item_id()));
+    let mut initialized = None;
+
+    for data in mir.basic_blocks() {
+        let (arg, target, span, lint_root) = match is_forget(mir, data, forget_fn) {
+            Some(call) => call,
+            None => continue,
+        };
+
+        // The argument is usually a temporary moved out of, or borrowed
+        // from, the local the user wrote, which is the local whose drop we
+        // care about.
+        let local = moved_from(data, arg);
+        let decl = &mir.local_decls[local];
+        if !decl.ty.needs_drop(tcx, param_env) {
+            continue;
+        }
+
+        if !liveness.drop.ins[target].contains(&local) {
+            continue;
+        }
+
+        let initialized = initialized.get_or_insert_with(|| {
+            Initialized::compute(tcx, source, mir)
+        });
+        if !reaches_initialized_drop(mir, initialized, target, local) {
+            continue;
+        }
+
+        let name = match decl.name {
+            Some(name) => format!("`{}`", name),
+            None => format!("value"),
+        };
+        let lint_root = lint_root.unwrap_or(source.item_id());
+        tcx.struct_span_lint_node(FORGET_WHILE_DROP_LIVE,
+                                  lint_root,
+                                  span,
+                                  &format!("{} is still dropped after the call to `mem::forget`",
+                                           name))
+            .span_label(decl.source_info.span, "a drop of this value is still scheduled")
+            .note("`mem::forget` did not take ownership of it, so its destructor will still run")
+            .emit();
+    }
+}
+
+/// If `data` ends in a call to `mem::forget` whose argument is a local,
+/// returns that local, the return target of the call, its span and the
+/// node whose lint levels apply to it.
+fn is_forget<'tcx>(mir: &Mir<'tcx>,
+                   data: &BasicBlockData<'tcx>,
+                   forget_fn: DefId)
+                   -> Option<(Local, BasicBlock, Span, Option<NodeId>)> {
+    let terminator = data.terminator();
+    if let TerminatorKind::Call { func: ref oper, ref args, ref destination, .. } =
+        terminator.kind
+    {
+        if let Operand::Constant(ref func) = *oper {
+            if let ty::TyFnDef(def_id, _) = func.ty.sty {
+                if def_id != forget_fn {
+                    return None;
+                }
+                if let (Some(&Operand::Consume(Lvalue::Local(arg))), &Some((_, target))) =
+                    (args.get(0), destination)
+                {
+                    let lint_root = match mir.visibility_scope_info {
+                        ClearOnDecode::Set(ref info) => {
+                            Some(info[terminator.source_info.scope].lint_root)
+                        }
+                        ClearOnDecode::Clear => None,
+                    };
+                    return Some((arg, target, terminator.source_info.span, lint_root));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Looks through a trailing `temp = local` move, or `temp = &local`
+/// borrow, in `data` to find the local that `temp` came from.
+fn moved_from<'tcx>(data: &BasicBlockData<'tcx>, temp: Local) -> Local {
+    for statement in data.statements.iter().rev() {
+        if let StatementKind::Assign(Lvalue::Local(dest), ref rvalue) = statement.kind {
+            if dest == temp {
+                match *rvalue {
+                    Rvalue::Use(Operand::Consume(Lvalue::Local(src))) |
+                    Rvalue::Ref(_, _, Lvalue::Local(src)) => return src,
+                    _ => break,
+                }
+            }
+        }
+    }
+    temp
+}
+
+/// Whether a drop of `local` that may find it initialized is reachable
+/// from the start of `start` before `local` is assigned a new value,
+/// whose drop would have nothing to do with the forgotten one.
+fn reaches_initialized_drop<'tcx>(mir: &Mir<'tcx>,
+                                  initialized: &Initialized,
+                                  start: BasicBlock,
+                                  local: Local)
+                                  -> bool {
+    let mut visited = IdxSetBuf::new_empty(mir.basic_blocks().len());
+    let mut stack = vec![start];
+    'blocks: while let Some(block) = stack.pop() {
+        if !visited.add(&block) {
+            continue;
+        }
+        let data = &mir[block];
+        for statement in &data.statements {
+            if let StatementKind::Assign(Lvalue::Local(dest), _) = statement.kind {
+                if dest == local {
+                    continue 'blocks;
+                }
+            }
+        }
+        let terminator = data.terminator();
+        match terminator.kind {
+            TerminatorKind::Drop { location: Lvalue::Local(dropped), .. } if dropped == local => {
+                if initialized.maybe_before_terminator(mir, block, local) {
+                    return true;
+                }
+            }
+            TerminatorKind::DropAndReplace { location: Lvalue::Local(dropped), .. }
+                if dropped == local => {
+                if initialized.maybe_before_terminator(mir, block, local) {
+                    return true;
+                }
+                continue;
+            }
+            TerminatorKind::Call { destination: Some((Lvalue::Local(dest), _)), .. }
+                if dest == local => continue,
+            _ => {}
+        }
+        stack.extend(terminator.successors().iter().cloned());
+    }
+    false
+}
@@ -0,0 +1,195 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Finds the locals that are initialized on some, but not all, paths
+//! into each basic block, e.g. after an `if` that moves a value out of
+//! a local on one arm only.
+//!
+//! This only looks at whole locals. A local is initialized by a store or
+//! a call into it and uninitialized by `StorageLive`, `StorageDead`, a
+//! drop, or a move out of it. A use of a local counts as a move if its
+//! type needs drop, as such types are never `Copy`. The destination of
+//! a call is treated as initialized on the unwind edge as well.
+
+use rustc::mir::{BasicBlock, Local, Location, Lvalue, Mir, Rvalue, TerminatorKind};
+use rustc::mir::transform::MirSource;
+use rustc::mir::visit::{LvalueContext, Visitor};
+use rustc::ty::TyCtxt;
+use rustc_data_structures::indexed_set::IdxSetBuf;
+use rustc_data_structures::indexed_vec::{Idx, IndexVec};
+use util::liveness::LocalSet;
+
+/// Returns, for each block of `mir`, the locals that are initialized on
+/// entry to it along some paths but not along others.
+pub fn conditionally_initialized<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                           source: MirSource,
+                                           mir: &Mir<'tcx>)
+                                           -> IndexVec<BasicBlock, LocalSet> {
+    let Initialized { maybe, definitely, .. } = Initialized::compute(tcx, source, mir);
+    maybe.into_iter_enumerated().map(|(block, mut bits)| {
+        bits.subtract(&definitely[block]);
+        bits
+    }).collect()
+}
+
+/// The locals initialized on entry to each basic block.
+pub struct Initialized {
+    moved_by_use: LocalSet,
+    /// Initialized along some path into the block.
+    pub maybe: IndexVec<BasicBlock, LocalSet>,
+    /// Initialized along every path into the block.
+    pub definitely: IndexVec<BasicBlock, LocalSet>,
+}
+
+impl Initialized {
+    pub fn compute<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                             source: MirSource,
+                             mir: &Mir<'tcx>)
+                             -> Initialized {
+        let param_env = tcx.param_env(tcx.hir.local_def_id(source.item_id()));
+        let locals = mir.local_decls.len();
+
+        let mut moved_by_use = LocalSet::new_empty(locals);
+        for (local, decl) in mir.local_decls.iter_enumerated() {
+            if decl.ty.needs_drop(tcx, param_env) {
+                moved_by_use.add(&local);
+            }
+        }
+
+        // The arguments are initialized on entry; nothing else is.
+        let mut args = LocalSet::new_empty(locals);
+        for local in mir.args_iter() {
+            args.add(&local);
+        }
+
+        // Initialized on some path (grows from nothing) and on every path
+        // (shrinks from everything) into each block.
+        let mut maybe: IndexVec<BasicBlock, LocalSet> = mir.basic_blocks()
+            .indices()
+            .map(|_| LocalSet::new_empty(locals)).collect();
+        let mut definitely: IndexVec<BasicBlock, LocalSet> = mir.basic_blocks()
+            .indices()
+            .map(|_| LocalSet::new_filled(locals)).collect();
+        let start = BasicBlock::new(0);
+        maybe[start].clone_from(&args);
+        definitely[start].clone_from(&args);
+
+        // Popping from the end visits the blocks in order first, which
+        // suits a forwards analysis.
+        let mut dirty_queue: Vec<BasicBlock> = mir.basic_blocks().indices().rev().collect();
+        let mut dirty_set = IdxSetBuf::new_filled(mir.basic_blocks().len());
+
+        let mut maybe_bits = LocalSet::new_empty(locals);
+        let mut definitely_bits = LocalSet::new_empty(locals);
+        while let Some(b) = dirty_queue.pop() {
+            dirty_set.remove(&b);
+
+            maybe_bits.clone_from(&maybe[b]);
+            transfer(mir, b, &moved_by_use, &mut maybe_bits);
+            definitely_bits.clone_from(&definitely[b]);
+            transfer(mir, b, &moved_by_use, &mut definitely_bits);
+
+            for &successor in mir.basic_blocks()[b].terminator().successors().iter() {
+                let changed = maybe[successor].union(&maybe_bits) |
+                              definitely[successor].intersect(&definitely_bits);
+                if changed && dirty_set.add(&successor) {
+                    dirty_queue.push(successor);
+                }
+            }
+        }
+
+        Initialized { moved_by_use, maybe, definitely }
+    }
+
+    /// Whether `local` is initialized along some path to the terminator of
+    /// `block`, i.e. after the block's statements have run.
+    pub fn maybe_before_terminator<'tcx>(&self,
+                                         mir: &Mir<'tcx>,
+                                         block: BasicBlock,
+                                         local: Local)
+                                         -> bool {
+        let mut bits = self.maybe[block].clone();
+        let mut visitor = InitVisitor { moved_by_use: &self.moved_by_use, bits: &mut bits };
+        for (index, statement) in mir[block].statements.iter().enumerate() {
+            visitor.visit_statement(block, statement, Location { block, statement_index: index });
+        }
+        bits.contains(&local)
+    }
+}
+
+/// Applies the effect of `block` on which locals are initialized to `bits`.
+fn transfer<'tcx>(mir: &Mir<'tcx>,
+                  block: BasicBlock,
+                  moved_by_use: &LocalSet,
+                  bits: &mut LocalSet) {
+    let mut visitor = InitVisitor { moved_by_use, bits };
+    visitor.visit_basic_block_data(block, &mir[block]);
+}
+
+struct InitVisitor<'a> {
+    moved_by_use: &'a LocalSet,
+    bits: &'a mut LocalSet,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for InitVisitor<'a> {
+    fn visit_assign(&mut self,
+                    _block: BasicBlock,
+                    lvalue: &Lvalue<'tcx>,
+                    rvalue: &Rvalue<'tcx>,
+                    location: Location) {
+        // The right-hand side is evaluated, and possibly moved out of,
+        // before the left-hand side is written.
+        self.visit_rvalue(rvalue, location);
+        self.visit_lvalue(lvalue, LvalueContext::Store, location);
+    }
+
+    fn visit_terminator_kind(&mut self,
+                             block: BasicBlock,
+                             kind: &TerminatorKind<'tcx>,
+                             location: Location) {
+        match *kind {
+            // The old value is dropped, but the local ends up initialized.
+            TerminatorKind::DropAndReplace { location: ref lvalue, ref value, .. } => {
+                self.visit_operand(value, location);
+                self.visit_lvalue(lvalue, LvalueContext::Store, location);
+            }
+            _ => self.super_terminator_kind(block, kind, location),
+        }
+    }
+
+    fn visit_local(&mut self,
+                   &local: &Local,
+                   context: LvalueContext<'tcx>,
+                   _: Location) {
+        match context {
+            LvalueContext::Store |
+            LvalueContext::Call => {
+                self.bits.add(&local);
+            }
+
+            LvalueContext::StorageLive |
+            LvalueContext::StorageDead |
+            LvalueContext::Drop => {
+                self.bits.remove(&local);
+            }
+
+            LvalueContext::Consume => {
+                if self.moved_by_use.contains(&local) {
+                    self.bits.remove(&local);
+                }
+            }
+
+            LvalueContext::Projection(..) |
+            LvalueContext::Borrow { .. } |
+            LvalueContext::Inspect |
+            LvalueContext::Validate => {}
+        }
+    }
+}
@@ -12,11 +12,12 @@ use self::infer::InferenceContext;
 use rustc::ty::TypeFoldable;
 use rustc::ty::subst::{Kind, Substs};
 use rustc::ty::{Ty, TyCtxt, ClosureSubsts, RegionVid, RegionKind};
-use rustc::mir::{Mir, Location, Rvalue, BasicBlock, Statement, StatementKind};
+use rustc::mir::{Mir, Location, Lvalue, Local, Rvalue, BasicBlock, Statement, StatementKind,
+                 TerminatorKind};
 use rustc::mir::visit::{MutVisitor, Lookup};
 use rustc::mir::transform::{MirPass, MirSource};
 use rustc::infer::{self as rustc_infer, InferCtxt};
-use rustc::util::nodemap::FxHashSet;
+use rustc::util::nodemap::{FxHashMap, FxHashSet};
 use rustc_data_structures::indexed_vec::{IndexVec, Idx};
 use syntax_pos::DUMMY_SP;
 use std::collections::HashMap;
@@ -24,8 +25,16 @@ use std::fmt;
 
 use util as mir_util;
 use self::mir_util::PassWhere;
+use util::liveness::{LivenessResults, LocalSet};
 
+mod drops;
+mod facts;
+mod fixpoint;
+mod forget;
 mod infer;
+mod init;
+mod storage;
+mod update;
 
 #[allow(dead_code)]
 struct NLLVisitor<'a, 'gcx: 'a + 'tcx, 'tcx: 'a> {
@@ -153,14 +162,161 @@ impl MirPass for NLL {
             let mut renumbered_mir = mir.clone();
             let mut visitor = NLLVisitor::new(&infcx);
             visitor.visit_mir(&mut renumbered_mir);
+            let liveness = LivenessResults::compute(&renumbered_mir);
+            if tcx.sess.opts.debugging_opts.dump_liveness_stats {
+                mir_util::liveness::print_stats(tcx, source, &renumbered_mir, &liveness);
+            }
+            if tcx.sess.opts.debugging_opts.nll_facts {
+                let dir = &tcx.sess.opts.debugging_opts.nll_facts_dir;
+                if let Err(e) = facts::write_liveness_facts(dir, source, &renumbered_mir,
+                                                            &liveness) {
+                    tcx.sess.err(&format!("failed to write NLL facts to `{}`: {}", dir, e));
+                }
+            }
+            let conditionally_initialized = init::conditionally_initialized(tcx, source, mir);
+            let moves = locals_moved_by_default(tcx, source, mir);
+            let mut block_liveness = FxHashMap();
+            let mut block_unconditional_drops = FxHashMap();
             mir_util::dump_mir(tcx, None, "nll", &0, source, mir, |pass_where, out| {
-                if let PassWhere::BeforeCFG = pass_where {
-                    for (index, value) in visitor.regions.iter_enumerated() {
-                        writeln!(out, "// R{:03}: {:?}", index.0, value)?;
+                match pass_where {
+                    // Before the CFG, dump out the values for each region variable,
+                    // the locals that are ever drop-live and the dominating uses
+                    // of the arguments.
+                    PassWhere::BeforeCFG => {
+                        for (index, value) in visitor.regions.iter_enumerated() {
+                            writeln!(out, "// R{:03}: {:?}", index.0, value)?;
+                        }
+
+                        let drop_live: Vec<_> = renumbered_mir.local_decls.indices()
+                            .filter(|&local| liveness.any_drop_live(&renumbered_mir, local))
+                            .collect();
+                        writeln!(out, "| Drop-live locals: {:?}", drop_live)?;
+
+                        for local in renumbered_mir.args_iter() {
+                            writeln!(out, "| Dominating uses of {:?}: {:?}",
+                                     local, liveness.dominating_uses(&renumbered_mir, local))?;
+                        }
+                    }
+
+                    // Before each basic block, dump out the locals live on entry
+                    // and remember the locals live at each point within the block.
+                    PassWhere::BeforeBlock(bb) => {
+                        // A local is live if it is live for either a regular use or a drop.
+                        let mut live = liveness.regular.ins[bb].clone();
+                        live.union(&liveness.drop.ins[bb]);
+                        let s = live_variable_set(&live);
+                        writeln!(out, "    | Live variables on entry to {:?}: {}", bb, s)?;
+
+                        // Locals that may or may not still hold a value to
+                        // drop, depending on the path taken to get here.
+                        let mut maybe_drop_live = liveness.drop.ins[bb].clone();
+                        maybe_drop_live.intersect(&conditionally_initialized[bb]);
+                        if maybe_drop_live.iter().next().is_some() {
+                            let s = live_variable_set(&maybe_drop_live);
+                            writeln!(out, "    | Maybe-drop-live on entry to {:?}: {}", bb, s)?;
+                        }
+
+                        block_liveness.clear();
+                        liveness.regular.simulate_block(&renumbered_mir, bb, |location, bits| {
+                            block_liveness.insert(location, bits.clone());
+                        });
+                        liveness.drop.simulate_block(&renumbered_mir, bb, |location, bits| {
+                            block_liveness.get_mut(&location).unwrap().union(bits);
+                        });
+
+                        block_unconditional_drops.clear();
+                        liveness.unconditional_drop.simulate_block(&renumbered_mir, bb,
+                                                                   |location, bits| {
+                            block_unconditional_drops.insert(location, bits.clone());
+                        });
+                    }
+
+                    // Before each statement or terminator, dump out the locals
+                    // live on entry to it.
+                    PassWhere::InCFG(location) => {
+                        let s = live_variable_set(&block_liveness[&location]);
+                        writeln!(out, "        | Live variables at {:?}: {}", location, s)?;
+
+                        // Locals dropped on every path from here don't need
+                        // a drop flag; only mention them when there are any.
+                        let unconditional = &block_unconditional_drops[&location];
+                        if unconditional.iter().next().is_some() {
+                            let s = live_variable_set(unconditional);
+                            writeln!(out, "        | Unconditionally drop-live at {:?}: {}",
+                                     location, s)?;
+                        }
+
+                        // A call's arguments die at its terminator while its
+                        // destination becomes live, so show both sides.
+                        let data = &renumbered_mir[location.block];
+                        if location.statement_index == data.statements.len() {
+                            if let TerminatorKind::Call { .. } = data.terminator().kind {
+                                let (mut before, mut after) = liveness.regular
+                                    .liveness_at_terminator(&renumbered_mir, location.block);
+                                let (drop_before, drop_after) = liveness.drop
+                                    .liveness_at_terminator(&renumbered_mir, location.block);
+                                before.union(&drop_before);
+                                after.union(&drop_after);
+                                writeln!(out,
+                                         "        | Live variables before terminator of {:?}: {}",
+                                         location.block, live_variable_set(&before))?;
+                                writeln!(out,
+                                         "        | Live variables after terminator of {:?}: {}",
+                                         location.block, live_variable_set(&after))?;
+                            }
+                        } else if tcx.sess.opts.debugging_opts.dump_dead_stores {
+                            let next = Location {
+                                statement_index: location.statement_index + 1,
+                                ..location
+                            };
+                            let live_after = &block_liveness[&next];
+                            if let Some(local) = dead_store(&renumbered_mir, location, live_after) {
+                                writeln!(out, "        | Dead store candidate at {:?}: {:?}",
+                                         location, local)?;
+                            }
+                        }
+
+                        if tcx.sess.opts.debugging_opts.dump_liveness_kills {
+                            let live_after = if location.statement_index == data.statements.len() {
+                                let mut after = liveness.regular.outs[location.block].clone();
+                                after.union(&liveness.drop.outs[location.block]);
+                                after
+                            } else {
+                                let next = Location {
+                                    statement_index: location.statement_index + 1,
+                                    ..location
+                                };
+                                block_liveness[&next].clone()
+                            };
+                            let kills = mir_util::liveness::kills_at(&renumbered_mir, location,
+                                                                     &live_after, &moves);
+                            if !kills.is_empty() {
+                                let kills: Vec<_> = kills.iter()
+                                    .map(|&(local, reason)| format!("{:?}: {:?}", local, reason))
+                                    .collect();
+                                writeln!(out, "        | Kills at {:?}: [{}]",
+                                         location, kills.join(", "))?;
+                            }
+                        }
                     }
+
+                    PassWhere::AfterCFG => {}
                 }
                 Ok(())
             });
+            forget::check_forgotten_drop_live(tcx, source, mir, &liveness);
+            if tcx.sess.opts.debugging_opts.verify_storage_markers {
+                storage::verify_storage_markers(&renumbered_mir, &liveness);
+            }
+            if tcx.sess.opts.debugging_opts.verify_drops {
+                drops::verify_no_use_after_drop(&renumbered_mir, &liveness);
+            }
+            if tcx.sess.opts.debugging_opts.verify_liveness_update {
+                update::verify_liveness_update(&renumbered_mir, &liveness);
+            }
+            if tcx.sess.opts.debugging_opts.verify_liveness {
+                fixpoint::verify_liveness(&renumbered_mir, &liveness);
+            }
             let (_lookup_map, regions) = visitor.into_results();
             let mut inference_context = InferenceContext::new(regions);
             inference_context.solve(&infcx, &renumbered_mir);
@@ -168,6 +324,39 @@ impl MirPass for NLL {
     }
 }
 
+/// The locals whose type is moved, rather than copied, when consumed.
+fn locals_moved_by_default<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                     source: MirSource,
+                                     mir: &Mir<'tcx>)
+                                     -> LocalSet {
+    let param_env = tcx.param_env(tcx.hir.local_def_id(source.item_id()));
+    let mut moves = LocalSet::new_empty(mir.local_decls.len());
+    for (local, decl) in mir.local_decls.iter_enumerated() {
+        if decl.ty.moves_by_default(tcx, param_env, decl.source_info.span) {
+            moves.add(&local);
+        }
+    }
+    moves
+}
+
+fn live_variable_set(live: &LocalSet) -> String {
+    let live: Vec<_> = live.iter().collect();
+    format!("{:?}", live)
+}
+
+/// If the statement at `location` overwrites a whole local that is
+/// neither used nor dropped afterwards, returns that local. Such a store
+/// could be removed, as long as its rvalue has no side effects.
+fn dead_store(mir: &Mir, location: Location, live_after: &LocalSet) -> Option<Local> {
+    let statement = &mir[location.block].statements[location.statement_index];
+    match statement.kind {
+        StatementKind::Assign(Lvalue::Local(local), _) if !live_after.contains(&local) => {
+            Some(local)
+        }
+        _ => None,
+    }
+}
+
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct Region {
     points: FxHashSet<Location>,
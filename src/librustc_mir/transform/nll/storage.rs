@@ -0,0 +1,69 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! With `-Z verify-storage-markers`, checks the `StorageLive` and
+//! `StorageDead` statements emitted by the MIR builder against liveness.
+//! Walking backwards from any use of a local, liveness stops at a
+//! definition of it, which includes both storage statements. So a local
+//! that is used outside of its storage is live either on entry to the
+//! function or right after a `StorageDead` of it, and those are the two
+//! places checked here.
+//!
+//! Locals without storage statements, such as the return place and the
+//! arguments, are allocated for the whole function and are not checked.
+//! A store into dead storage also ends liveness, so it is not caught.
+
+use rustc::mir::{Location, Mir, StatementKind, START_BLOCK};
+use util::liveness::{LivenessResults, LocalSet};
+
+pub fn verify_storage_markers<'tcx>(mir: &Mir<'tcx>, liveness: &LivenessResults) {
+    let mut has_storage = LocalSet::new_empty(mir.local_decls.len());
+    for data in mir.basic_blocks() {
+        for statement in &data.statements {
+            match statement.kind {
+                StatementKind::StorageLive(local) |
+                StatementKind::StorageDead(local) => {
+                    has_storage.add(&local);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut live_on_entry = liveness.regular.ins[START_BLOCK].clone();
+    live_on_entry.union(&liveness.drop.ins[START_BLOCK]);
+    live_on_entry.intersect(&has_storage);
+    if let Some(local) = live_on_entry.iter().next() {
+        span_bug!(mir.local_decls[local].source_info.span,
+                  "{:?} may be used before its `StorageLive`", local);
+    }
+
+    for (block, data) in mir.basic_blocks().iter_enumerated() {
+        // The locals live on entry to each statement and the terminator.
+        let mut live = vec![LocalSet::new_empty(0); data.statements.len() + 1];
+        liveness.regular.simulate_block(mir, block, |location, bits| {
+            live[location.statement_index] = bits.clone();
+        });
+        liveness.drop.simulate_block(mir, block, |location, bits| {
+            live[location.statement_index].union(bits);
+        });
+
+        for (statement_index, statement) in data.statements.iter().enumerate() {
+            if let StatementKind::StorageDead(local) = statement.kind {
+                if live[statement_index + 1].contains(&local) {
+                    let location = Location { block, statement_index };
+                    span_bug!(statement.source_info.span,
+                              "{:?} may be used after its `StorageDead` at {:?}",
+                              local, location);
+                }
+            }
+        }
+    }
+}
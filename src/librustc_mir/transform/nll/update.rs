@@ -0,0 +1,46 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Checks `LivenessResults::update` against a full recomputation
+//! (`-Z verify-liveness-update`). For each block in turn, the statements
+//! of the block are replaced with `Nop`s, which removes all of its uses
+//! and definitions, and the updated results must equal the results
+//! computed from scratch for the edited MIR.
+
+use rustc::mir::Mir;
+use util::liveness::{LivenessResult, LivenessResults};
+
+pub fn verify_liveness_update<'tcx>(mir: &Mir<'tcx>, liveness: &LivenessResults) {
+    for block in mir.basic_blocks().indices() {
+        if mir[block].statements.is_empty() {
+            continue;
+        }
+
+        let mut edited = mir.clone();
+        for statement in &mut edited.basic_blocks_mut()[block].statements {
+            statement.make_nop();
+        }
+
+        let mut updated = liveness.clone();
+        updated.update(&edited, &[block]);
+        let expected = LivenessResults::compute(&edited);
+        if !same_sets(&updated.regular, &expected.regular) ||
+           !same_sets(&updated.drop, &expected.drop) ||
+           !same_sets(&updated.unconditional_drop, &expected.unconditional_drop) {
+            span_bug!(mir.span,
+                      "updating liveness after editing {:?} differs from recomputing it",
+                      block);
+        }
+    }
+}
+
+fn same_sets(a: &LivenessResult, b: &LivenessResult) -> bool {
+    a.ins == b.ins && a.outs == b.outs
+}
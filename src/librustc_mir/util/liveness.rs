@@ -33,6 +33,7 @@
 //! generator yield points, all pre-existing references are invalidated, so this
 //! doesn't matter).
 
+use rustc::hir::InlineAsm;
 use rustc::mir::*;
 use rustc::mir::visit::{LvalueContext, Visitor};
 use rustc_data_structures::indexed_vec::{IndexVec, Idx};
@@ -47,19 +48,142 @@ use std::io::{self, Write};
 
 pub type LocalSet = IdxSetBuf<Local>;
 
+/// Determines which kinds of uses of a local make it live.
+#[derive(Copy, Clone, Debug)]
+pub struct LivenessMode {
+    /// If true, then a "regular use" of a local, such as `foo(x)`,
+    /// makes it live.
+    pub include_regular_use: bool,
+
+    /// If true, then a drop of a local, such as the implicit drop at the
+    /// end of the scope of `let x = vec![...];`, makes it live. A call to
+    /// `::std::mem::drop(x)` is a regular use, not a drop.
+    pub include_drops: bool,
+}
+
+/// The effect of a statement, terminator or whole basic block on
+/// liveness: the locals it defines (kill) and the locals it uses before
+/// defining them (gen).
 #[derive(Eq, PartialEq, Clone)]
-struct BlockInfo {
-    defs: LocalSet,
-    uses: LocalSet,
+struct DefsUses<I: Idx = Local> {
+    defs: IdxSetBuf<I>,
+    uses: IdxSetBuf<I>,
+}
+
+impl<I: Idx> DefsUses<I> {
+    /// Transforms `bits` from the set of locals live on exit to the set
+    /// of locals live on entry, i.e. `bits = uses ∪ (bits - defs)`.
+    fn apply(&self, bits: &mut IdxSetBuf<I>) -> bool {
+        bits.subtract(&self.defs) | bits.union(&self.uses)
+    }
+}
+
+newtype_index!(LiveVar);
+
+/// Numbers the locals that the fixpoint in `compute_liveness` has to
+/// track. Only a local that some block uses before defining it can be
+/// live on entry to a block; temporaries that are never read, or only
+/// read within the block that wrote them, never are and are left out of
+/// the bitsets altogether.
+struct LiveVarMap {
+    from_local: IndexVec<Local, Option<LiveVar>>,
+    to_local: IndexVec<LiveVar, Local>,
 }
 
-struct BlockInfoVisitor {
+impl LiveVarMap {
+    fn new(locals: usize, def_use: &IndexVec<BasicBlock, DefsUses>) -> LiveVarMap {
+        let mut used = LocalSet::new_empty(locals);
+        for du in def_use {
+            used.union(&du.uses);
+        }
+
+        let mut from_local = IndexVec::from_elem_n(None, locals);
+        let mut to_local = IndexVec::new();
+        for local in (0..locals).map(Local::new) {
+            if used.contains(&local) {
+                from_local[local] = Some(to_local.push(local));
+            }
+        }
+        LiveVarMap { from_local, to_local }
+    }
+
+    fn compact(&self, set: &LocalSet) -> IdxSetBuf<LiveVar> {
+        let mut result = IdxSetBuf::new_empty(self.to_local.len());
+        for (var, local) in self.to_local.iter_enumerated() {
+            if set.contains(local) {
+                result.add(&var);
+            }
+        }
+        result
+    }
+
+    fn expand(&self, set: &IdxSetBuf<LiveVar>) -> LocalSet {
+        let mut result = LocalSet::new_empty(self.from_local.len());
+        for (var, local) in self.to_local.iter_enumerated() {
+            if set.contains(&var) {
+                result.add(local);
+            }
+        }
+        result
+    }
+}
+
+struct DefsUsesVisitor {
+    mode: LivenessMode,
     pre_defs: LocalSet,
     defs: LocalSet,
     uses: LocalSet,
 }
 
-impl<'tcx> Visitor<'tcx> for BlockInfoVisitor {
+impl DefsUsesVisitor {
+    fn new(mode: LivenessMode, locals: usize) -> DefsUsesVisitor {
+        DefsUsesVisitor {
+            mode,
+            pre_defs: LocalSet::new_empty(locals),
+            defs: LocalSet::new_empty(locals),
+            uses: LocalSet::new_empty(locals),
+        }
+    }
+
+    fn into_defs_uses(self) -> DefsUses {
+        DefsUses {
+            defs: self.defs,
+            uses: self.uses,
+        }
+    }
+
+    fn add_use(&mut self, local: Local) {
+        // Ignore uses which are already defined in this block
+        if !self.pre_defs.contains(&local) {
+            self.uses.add(&local);
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for DefsUsesVisitor {
+    fn visit_terminator_kind(&mut self,
+                             block: BasicBlock,
+                             kind: &TerminatorKind<'tcx>,
+                             location: Location) {
+        // The return place isn't mentioned by `return`, but its value is
+        // what gets returned, so it is live up to there.
+        if let TerminatorKind::Return = *kind {
+            self.visit_local(&RETURN_POINTER, LvalueContext::Consume, location);
+        }
+        self.super_terminator_kind(block, kind, location);
+    }
+
+    fn visit_statement(&mut self,
+                       block: BasicBlock,
+                       statement: &Statement<'tcx>,
+                       location: Location) {
+        if let StatementKind::InlineAsm { ref asm, ref outputs, ref inputs } = statement.kind {
+            visit_inline_asm(self, asm, outputs, inputs, location);
+        } else {
+            self.super_statement(block, statement, location);
+        }
+    }
+
     fn visit_local(&mut self,
                    &local: &Local,
                    context: LvalueContext<'tcx>,
@@ -87,27 +211,55 @@ impl<'tcx> Visitor<'tcx> for BlockInfoVisitor {
 
             LvalueContext::Inspect |
             LvalueContext::Consume |
-            LvalueContext::Validate |
+            LvalueContext::Validate => {
+                if self.mode.include_regular_use {
+                    self.add_use(local);
+                }
+            }
 
             // We consider drops to always be uses of locals.
             // Drop eloboration should be run before this analysis otherwise
             // the results might be too pessimistic.
             LvalueContext::Drop => {
-                // Ignore uses which are already defined in this block
-                if !self.pre_defs.contains(&local) {
-                    self.uses.add(&local);
+                if self.mode.include_drops {
+                    self.add_use(local);
                 }
             }
         }
     }
 }
 
-fn block<'tcx>(b: &BasicBlockData<'tcx>, locals: usize) -> BlockInfo {
-    let mut visitor = BlockInfoVisitor {
-        pre_defs: LocalSet::new_empty(locals),
-        defs: LocalSet::new_empty(locals),
-        uses: LocalSet::new_empty(locals),
-    };
+/// Visits the operands of an `InlineAsm` statement. The assembly writes
+/// its outputs, but a read-write (`+`) output is also read, and an
+/// indirect (`*`) one is a pointer that the assembly writes through, so
+/// it is only read.
+fn visit_inline_asm<'tcx, V: Visitor<'tcx>>(visitor: &mut V,
+                                            asm: &InlineAsm,
+                                            outputs: &[Lvalue<'tcx>],
+                                            inputs: &[Operand<'tcx>],
+                                            location: Location) {
+    for (output, spec) in outputs.iter().zip(&asm.outputs) {
+        if spec.is_rw || spec.is_indirect {
+            visitor.visit_lvalue(output, LvalueContext::Inspect, location);
+        }
+        if !spec.is_indirect {
+            visitor.visit_lvalue(output, LvalueContext::Store, location);
+        }
+    }
+    for input in inputs {
+        visitor.visit_operand(input, location);
+    }
+}
+
+fn block<'tcx>(mode: LivenessMode, b: &BasicBlockData<'tcx>, locals: usize) -> DefsUses {
+    let mut visitor = DefsUsesVisitor::new(mode, locals);
+
+    // Control never reaches the end of a block that ends in `Unreachable`,
+    // so nothing is live anywhere in it, and its uses must not make locals
+    // live in the blocks leading to it.
+    if let TerminatorKind::Unreachable = b.terminator().kind {
+        return visitor.into_defs_uses();
+    }
 
     let dummy_location = Location { block: BasicBlock::new(0), statement_index: 0 };
 
@@ -117,64 +269,528 @@ fn block<'tcx>(b: &BasicBlockData<'tcx>, locals: usize) -> BlockInfo {
     }
     visitor.visit_terminator(BasicBlock::new(0), b.terminator(), dummy_location);
 
-    BlockInfo {
-        defs: visitor.defs,
-        uses: visitor.uses,
-    }
+    visitor.into_defs_uses()
 }
 
 // This gives the result of the liveness analysis at the boundary of basic blocks
+#[derive(Clone)]
 pub struct LivenessResult {
+    pub mode: LivenessMode,
     pub ins: IndexVec<BasicBlock, LocalSet>,
     pub outs: IndexVec<BasicBlock, LocalSet>,
+
+    /// The number of times a block's entry set was recomputed before
+    /// reaching the fixpoint.
+    pub rounds: usize,
+}
+
+/// Liveness results split by the kind of use, as used by NLL.
+#[derive(Clone)]
+pub struct LivenessResults {
+    /// Locals made live by a regular use, but not by a drop.
+    pub regular: LivenessResult,
+
+    /// Locals made live by a drop, but not by a regular use.
+    pub drop: LivenessResult,
+
+    /// The subset of `drop` that is dropped on every path, so whether
+    /// the local still needs dropping does not depend on the path taken
+    /// and its drop flag can be elided.
+    pub unconditional_drop: LivenessResult,
+}
+
+impl LivenessResults {
+    pub fn compute<'tcx>(mir: &Mir<'tcx>) -> LivenessResults {
+        let drop = liveness_of_locals(mir, LivenessMode {
+            include_regular_use: false,
+            include_drops: true,
+        });
+        LivenessResults {
+            regular: liveness_of_locals(mir, LivenessMode {
+                include_regular_use: true,
+                include_drops: false,
+            }),
+            unconditional_drop: unconditional_liveness_of_locals(mir, &drop),
+            drop,
+        }
+    }
+
+    /// Whether `local` is drop-live anywhere in `mir`, i.e. whether it may
+    /// ever still need dropping. A local drop-live at some point is either
+    /// live on entry to a later block or dropped by the terminator of its
+    /// own block, so only block entries and terminators are looked at.
+    pub fn any_drop_live<'tcx>(&self, mir: &Mir<'tcx>, local: Local) -> bool {
+        mir.basic_blocks().iter_enumerated().any(|(bb, data)| {
+            if self.drop.ins[bb].contains(&local) {
+                return true;
+            }
+            match data.terminator().kind {
+                TerminatorKind::Drop { location: Lvalue::Local(dropped), .. } |
+                TerminatorKind::DropAndReplace { location: Lvalue::Local(dropped), .. } => {
+                    dropped == local
+                }
+                _ => false,
+            }
+        })
+    }
+
+    /// The locations where `local` is used, in the sense of `regular`,
+    /// that are not dominated by another use of it. Every use is dominated
+    /// by one of these, so a pass sinking the computation of `local`
+    /// towards its uses has to stop at their nearest common dominator.
+    /// Uses in unreachable blocks are ignored.
+    pub fn dominating_uses<'tcx>(&self, mir: &Mir<'tcx>, local: Local) -> Vec<Location> {
+        let dominators = mir.dominators();
+        let mut uses = vec![];
+        for (block, data) in mir.basic_blocks().iter_enumerated() {
+            if !dominators.is_reachable(block) {
+                continue;
+            }
+            for statement_index in 0..data.statements.len() + 1 {
+                let location = Location { block, statement_index };
+                if self.regular.uses_at(mir, location).contains(&local) {
+                    uses.push(location);
+                }
+            }
+        }
+        uses.iter().cloned().filter(|location| {
+            !uses.iter().any(|other| other != location && other.dominates(location, &dominators))
+        }).collect()
+    }
+
+    /// Brings all three results up to date with `mir` after the blocks in
+    /// `modified` were edited; see `LivenessResult::update`.
+    ///
+    /// Only `regular` and `drop` are updated in place. The unconditional
+    /// results are intersected with `drop` once computed, which loses the
+    /// sets an update would have to restart from, so they are recomputed.
+    pub fn update<'tcx>(&mut self, mir: &Mir<'tcx>, modified: &[BasicBlock]) {
+        self.regular.update(mir, modified);
+        self.drop.update(mir, modified);
+        self.unconditional_drop = unconditional_liveness_of_locals(mir, &self.drop);
+    }
+}
+
+/// Compute which locals are live within `mir`. The liveness mode `mode`
+/// determines which sorts of uses make a local live.
+pub fn liveness_of_locals<'tcx>(mir: &Mir<'tcx>, mode: LivenessMode) -> LivenessResult {
+    compute_liveness(mir, mode, false)
 }
 
-pub fn liveness_of_locals<'tcx>(mir: &Mir<'tcx>) -> LivenessResult {
+/// Computes the same results as `liveness_of_locals`, but by revisiting
+/// every block in reverse order until nothing changes, tracking every
+/// local. This is much slower on large bodies; it only serves as the
+/// reference the worklist fixpoint is checked against (`-Z
+/// verify-liveness`).
+pub fn round_robin_liveness_of_locals<'tcx>(mir: &Mir<'tcx>,
+                                            mode: LivenessMode)
+                                            -> LivenessResult {
     let locals = mir.local_decls.len();
     let def_use: IndexVec<_, _> = mir.basic_blocks().iter().map(|b| {
-        block(b, locals)
+        block(mode, b, locals)
     }).collect();
 
-    let copy = |from: &IndexVec<BasicBlock, LocalSet>, to: &mut IndexVec<BasicBlock, LocalSet>| {
-        for (i, set) in to.iter_enumerated_mut() {
-            set.clone_from(&from[i]);
-        }
-    };
-
     let mut ins: IndexVec<_, _> = mir.basic_blocks()
         .indices()
         .map(|_| LocalSet::new_empty(locals)).collect();
     let mut outs = ins.clone();
 
-    let mut ins_ = ins.clone();
-    let mut outs_ = outs.clone();
-
-    loop {
-        copy(&ins, &mut ins_);
-        copy(&outs, &mut outs_);
-
+    let mut rounds = 0;
+    let mut changed = true;
+    while changed {
+        changed = false;
         for b in mir.basic_blocks().indices().rev() {
+            rounds += 1;
+
             // out = ∪ {ins of successors}
             outs[b].clear();
-            for &successor in mir.basic_blocks()[b].terminator().successors().into_iter() {
+            for &successor in mir.basic_blocks()[b].terminator().successors().iter() {
                 outs[b].union(&ins[successor]);
             }
 
             // in = use ∪ (out - def)
-            ins[b].clone_from(&outs[b]);
-            ins[b].subtract(&def_use[b].defs);
-            ins[b].union(&def_use[b].uses);
+            let mut bits = outs[b].clone();
+            def_use[b].apply(&mut bits);
+            if ins[b] != bits {
+                ins[b] = bits;
+                changed = true;
+            }
         }
+    }
 
-        if ins_ == ins && outs_ == outs {
-            break;
-        }
+    LivenessResult { mode, ins, outs, rounds }
+}
+
+/// Compute which locals are live on *every* path from each point of `mir`,
+/// rather than on some path, given the ordinary liveness results `live`.
+/// The result is always a subset of `live`: a local that no path uses is
+/// not considered unconditionally live, even inside a loop that never
+/// exits.
+pub fn unconditional_liveness_of_locals<'tcx>(mir: &Mir<'tcx>,
+                                              live: &LivenessResult)
+                                              -> LivenessResult {
+    let mut result = compute_liveness(mir, live.mode, true);
+
+    // Intersecting both ends of each block keeps the result consistent
+    // with `simulate_block`, as the transfer function distributes over
+    // intersection.
+    for block in mir.basic_blocks().indices() {
+        result.ins[block].intersect(&live.ins[block]);
+        result.outs[block].intersect(&live.outs[block]);
     }
+    result
+}
+
+/// Backwards dataflow over the locals of `mir`. If `all_paths` is false,
+/// a local is live if some successor needs it; otherwise every successor
+/// must need it, and blocks start out with everything live so that the
+/// fixpoint is the greatest one.
+fn compute_liveness<'tcx>(mir: &Mir<'tcx>,
+                          mode: LivenessMode,
+                          all_paths: bool)
+                          -> LivenessResult {
+    let locals = mir.local_decls.len();
+
+    // Every set below has a bit per local, so make sure that many bits
+    // can be stored before allocating any of them.
+    if IdxSetBuf::<Local>::num_words(locals).is_none() {
+        span_bug!(mir.span, "liveness: too many locals to track ({})", locals);
+    }
+
+    // The defs and uses of each block only depend on the block itself, so
+    // compute them once up front rather than on every iteration.
+    let def_use: IndexVec<_, _> = mir.basic_blocks().iter().map(|b| {
+        block(mode, b, locals)
+    }).collect();
+
+    // Run the fixpoint over just the locals that can be live somewhere.
+    let vars = LiveVarMap::new(locals, &def_use);
+    let def_use: IndexVec<BasicBlock, DefsUses<LiveVar>> = def_use.iter().map(|du| {
+        DefsUses {
+            defs: vars.compact(&du.defs),
+            uses: vars.compact(&du.uses),
+        }
+    }).collect();
+    let tracked = vars.to_local.len();
+
+    let mut ins: IndexVec<BasicBlock, IdxSetBuf<LiveVar>> = mir.basic_blocks()
+        .indices()
+        .map(|_| if all_paths {
+            IdxSetBuf::new_filled(tracked)
+        } else {
+            IdxSetBuf::new_empty(tracked)
+        }).collect();
+    let mut outs = ins.clone();
+
+    // Initially the entry set of every block may be stale.
+    let dirty: Vec<BasicBlock> = mir.basic_blocks().indices().collect();
+    let rounds = propagate(mir, all_paths, dirty, &mut ins, &mut outs, |b, bits| {
+        def_use[b].apply(bits);
+    });
 
     LivenessResult {
-        ins,
-        outs,
+        mode,
+        ins: ins.iter().map(|set| vars.expand(set)).collect(),
+        outs: outs.iter().map(|set| vars.expand(set)).collect(),
+        rounds,
+    }
+}
+
+/// Runs the fixpoint of `compute_liveness` from the given `ins` and
+/// `outs`, starting with the blocks in `dirty`. `transfer` turns the
+/// set of locals live on exit from a block into the set live on entry.
+/// Returns the number of times an entry set was recomputed.
+fn propagate<'tcx, I, F>(mir: &Mir<'tcx>,
+                         all_paths: bool,
+                         mut dirty_queue: Vec<BasicBlock>,
+                         ins: &mut IndexVec<BasicBlock, IdxSetBuf<I>>,
+                         outs: &mut IndexVec<BasicBlock, IdxSetBuf<I>>,
+                         transfer: F)
+                         -> usize
+    where I: Idx, F: Fn(BasicBlock, &mut IdxSetBuf<I>)
+{
+    // Blocks whose entry set may be stale. A block only needs to be
+    // revisited when the entry set of one of its successors changes.
+    // Popping from the end visits the blocks in reverse order first,
+    // which suits a backwards analysis.
+    let mut dirty_set = IdxSetBuf::new_empty(mir.basic_blocks().len());
+    for block in &dirty_queue {
+        dirty_set.add(block);
+    }
+
+    let predecessors = mir.predecessors();
+    let mut bits = match ins.iter().next() {
+        Some(set) => set.clone(),
+        None => return 0,
+    };
+    let mut rounds = 0;
+    while let Some(b) = dirty_queue.pop() {
+        dirty_set.remove(&b);
+        rounds += 1;
+
+        let successors = mir.basic_blocks()[b].terminator().successors();
+        if all_paths {
+            // out = ∩ {ins of successors}, or nothing at an exit
+            match successors.split_first() {
+                Some((&first, rest)) => {
+                    bits.clone_from(&ins[first]);
+                    for &successor in rest {
+                        bits.intersect(&ins[successor]);
+                    }
+                }
+                None => bits.clear(),
+            }
+        } else {
+            // out = ∪ {ins of successors}
+            bits.clear();
+            for &successor in successors.iter() {
+                bits.union(&ins[successor]);
+            }
+        }
+        outs[b].clone_from(&bits);
+
+        // in = use ∪ (out - def)
+        transfer(b, &mut bits);
+
+        if ins[b] != bits {
+            ins[b].clone_from(&bits);
+            for &predecessor in &predecessors[b] {
+                if dirty_set.add(&predecessor) {
+                    dirty_queue.push(predecessor);
+                }
+            }
+        }
+    }
+    rounds
+}
+
+impl LivenessResult {
+    /// Brings these results up to date with `mir` after the statements
+    /// or terminators of the blocks in `modified` were edited, without
+    /// recomputing them from scratch. Afterwards they are the same as a
+    /// full recomputation, and `rounds` counts only the update.
+    ///
+    /// Only the blocks that can reach a modified block are recomputed;
+    /// the liveness of any other block cannot have changed. If blocks
+    /// were added or removed, or the sets have no room for new locals,
+    /// the results are recomputed from scratch instead.
+    pub fn update<'tcx>(&mut self, mir: &Mir<'tcx>, modified: &[BasicBlock]) {
+        // New locals are fine as long as the sets have room for them: only
+        // the affected blocks can mention them, and those are recomputed.
+        let locals = mir.local_decls.len();
+        let words = LocalSet::new_empty(locals).words().len();
+        let same_shape = self.ins.len() == mir.basic_blocks().len() &&
+            self.ins.iter().next().map_or(true, |set| set.words().len() == words);
+        if !same_shape {
+            *self = liveness_of_locals(mir, self.mode);
+            return;
+        }
+
+        // A block's liveness only depends on the blocks reachable from it,
+        // so the affected blocks are those that reach a modified one.
+        let mut affected_set = IdxSetBuf::new_empty(mir.basic_blocks().len());
+        let mut affected = vec![];
+        let mut stack = modified.to_vec();
+        {
+            let predecessors = mir.predecessors();
+            while let Some(block) = stack.pop() {
+                if affected_set.add(&block) {
+                    affected.push(block);
+                    stack.extend(predecessors[block].iter().cloned());
+                }
+            }
+        }
+        affected.sort();
+
+        // Start the affected blocks over from the initial state of the
+        // fixpoint, so that a use removed by the edit is not kept alive
+        // by a stale set in a loop.
+        let mut def_use = IndexVec::from_elem_n(None, mir.basic_blocks().len());
+        for &block in &affected {
+            self.ins[block] = LocalSet::new_empty(locals);
+            self.outs[block] = LocalSet::new_empty(locals);
+            def_use[block] = Some(self::block(self.mode, &mir[block], locals));
+        }
+
+        self.rounds = propagate(mir, false, affected, &mut self.ins, &mut self.outs, |b, bits| {
+            match def_use[b] {
+                Some(ref du) => { du.apply(bits); }
+                None => bug!("liveness update: {:?} was not expected to change", b),
+            }
+        });
+    }
+
+    /// Walks backwards through the terminator and statements of `block`,
+    /// invoking `callback` with each location and the set of locals that
+    /// are live on entry to it.
+    pub fn simulate_block<'tcx, OP>(&self, mir: &Mir<'tcx>, block: BasicBlock, mut callback: OP)
+        where OP: FnMut(Location, &LocalSet)
+    {
+        let data = &mir[block];
+        let locals = mir.local_decls.len();
+
+        if let TerminatorKind::Unreachable = data.terminator().kind {
+            // Nothing is live in the block, see `block`.
+            let bits = LocalSet::new_empty(locals);
+            for statement_index in (0..data.statements.len() + 1).rev() {
+                callback(Location { block, statement_index }, &bits);
+            }
+            return;
+        }
+
+        let (mut bits, _) = self.liveness_at_terminator(mir, block);
+        let mut statement_index = data.statements.len();
+        callback(Location { block, statement_index }, &bits);
+
+        for statement in data.statements.iter().rev() {
+            statement_index -= 1;
+            let statement_location = Location { block, statement_index };
+            let mut visitor = DefsUsesVisitor::new(self.mode, locals);
+            visitor.visit_statement(block, statement, statement_location);
+            visitor.into_defs_uses().apply(&mut bits);
+            callback(statement_location, &bits);
+        }
+
+        assert_eq!(bits, self.ins[block]);
+    }
+
+    /// Returns the locals live immediately before and immediately after
+    /// the terminator of `block`. For a call, the arguments may die at
+    /// the terminator while the destination becomes live after it.
+    pub fn liveness_at_terminator<'tcx>(&self,
+                                        mir: &Mir<'tcx>,
+                                        block: BasicBlock)
+                                        -> (LocalSet, LocalSet) {
+        let data = &mir[block];
+        let location = Location { block, statement_index: data.statements.len() };
+
+        let after = self.outs[block].clone();
+        let mut before = after.clone();
+        let mut visitor = DefsUsesVisitor::new(self.mode, mir.local_decls.len());
+        visitor.visit_terminator(block, data.terminator(), location);
+        visitor.into_defs_uses().apply(&mut before);
+        (before, after)
+    }
+
+    /// Returns the locals that the statement or terminator at `location`
+    /// uses, counting only the kinds of uses selected by `self.mode`.
+    pub fn uses_at<'tcx>(&self, mir: &Mir<'tcx>, location: Location) -> LocalSet {
+        let data = &mir[location.block];
+        let mut visitor = DefsUsesVisitor::new(self.mode, mir.local_decls.len());
+        if location.statement_index == data.statements.len() {
+            visitor.visit_terminator(location.block, data.terminator(), location);
+        } else {
+            let statement = &data.statements[location.statement_index];
+            visitor.visit_statement(location.block, statement, location);
+        }
+        visitor.into_defs_uses().uses
+    }
+}
+
+/// Why a local stops being live at a location, going forwards.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KillReason {
+    /// The local is assigned a new value, which ends its previous one.
+    Overwrite,
+    /// The storage of the local ends.
+    StorageDead,
+    /// The local is moved out of and not used again.
+    Move,
+    /// The local is used for the last time, other than by a move.
+    LastUse,
+}
+
+/// Classifies the locals that the statement or terminator at `location`
+/// kills, in the order they appear in it. `live_after` is the set of
+/// locals live right after `location`, and `moves` the set of locals
+/// whose type is moved rather than copied when consumed.
+pub fn kills_at<'tcx>(mir: &Mir<'tcx>,
+                      location: Location,
+                      live_after: &LocalSet,
+                      moves: &LocalSet)
+                      -> Vec<(Local, KillReason)> {
+    let mut visitor = KillsVisitor { live_after, moves, kills: vec![] };
+    let data = &mir[location.block];
+    if location.statement_index == data.statements.len() {
+        visitor.visit_terminator(location.block, data.terminator(), location);
+    } else {
+        let statement = &data.statements[location.statement_index];
+        visitor.visit_statement(location.block, statement, location);
+    }
+    visitor.kills
+}
+
+struct KillsVisitor<'a> {
+    live_after: &'a LocalSet,
+    moves: &'a LocalSet,
+    kills: Vec<(Local, KillReason)>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for KillsVisitor<'a> {
+    fn visit_terminator_kind(&mut self,
+                             block: BasicBlock,
+                             kind: &TerminatorKind<'tcx>,
+                             location: Location) {
+        // As in `DefsUsesVisitor`, `return` uses the return place.
+        if let TerminatorKind::Return = *kind {
+            self.visit_local(&RETURN_POINTER, LvalueContext::Consume, location);
+        }
+        self.super_terminator_kind(block, kind, location);
+    }
+
+    fn visit_statement(&mut self,
+                       block: BasicBlock,
+                       statement: &Statement<'tcx>,
+                       location: Location) {
+        if let StatementKind::InlineAsm { ref asm, ref outputs, ref inputs } = statement.kind {
+            visit_inline_asm(self, asm, outputs, inputs, location);
+        } else {
+            self.super_statement(block, statement, location);
+        }
     }
+
+    fn visit_local(&mut self,
+                   &local: &Local,
+                   context: LvalueContext<'tcx>,
+                   _: Location) {
+        let reason = match context {
+            LvalueContext::Store |
+            LvalueContext::Call => KillReason::Overwrite,
+            LvalueContext::StorageDead => KillReason::StorageDead,
+            LvalueContext::StorageLive => return,
+
+            // Only a use that leaves the local dead kills it.
+            _ if self.live_after.contains(&local) => return,
+            LvalueContext::Consume if self.moves.contains(&local) => KillReason::Move,
+            LvalueContext::Consume |
+            LvalueContext::Projection(..) |
+            LvalueContext::Borrow { .. } |
+            LvalueContext::Inspect |
+            LvalueContext::Validate |
+            LvalueContext::Drop => KillReason::LastUse,
+        };
+        if !self.kills.contains(&(local, reason)) {
+            self.kills.push((local, reason));
+        }
+    }
+}
+
+/// Prints the size of `mir` and the number of rounds each liveness
+/// computation in `results` took to converge (`-Z dump-liveness-stats`).
+pub fn print_stats<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                             source: MirSource,
+                             mir: &Mir<'tcx>,
+                             results: &LivenessResults) {
+    let node_path = item_path::with_forced_impl_filename_line(|| {
+        tcx.item_path_str(tcx.hir.local_def_id(source.item_id()))
+    });
+    println!("liveness stats for `{}`: {} blocks, {} locals, \
+              {} rounds (regular), {} rounds (drop)",
+             node_path,
+             mir.basic_blocks().len(),
+             mir.local_decls.len(),
+             results.regular.rounds,
+             results.drop.rounds);
 }
 
 pub fn dump_mir<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
@@ -554,9 +554,19 @@ fn check_adjustments<'a, 'tcx>(v: &mut CheckCrateVisitor<'a, 'tcx>, e: &hir::Exp
             Adjust::UnsafeFnPointer |
             Adjust::ClosureFnPointer |
             Adjust::MutToConstPointer |
+            Adjust::ArrayToPointer |
+            Adjust::BorrowArrayElements |
+            Adjust::WrapUnit |
+            Adjust::ReinterpretSlice |
             Adjust::Borrow(_) |
             Adjust::Unsize => {}
 
+            Adjust::Box |
+            Adjust::BoxIntoRaw => {
+                v.promotable = false;
+                break;
+            }
+
             Adjust::Deref(ref overloaded) => {
                 if overloaded.is_some() {
                     v.promotable = false;
@@ -41,6 +41,13 @@
 //! auto-borrow later arguments but not earlier ones, which
 //! seems very confusing.
 //!
+//! The one exception is auto-borrowing: if `b` is a type variable that
+//! has *already* been unified with a reference type, we look through it
+//! (one level, without forcing anything) before deciding whether to
+//! auto-borrow. So after `v.push(&xs[..])` has fixed the element type of
+//! `v: Vec<_>` to `&[T]`, `v.push(&vec)` coerces `&Vec<T>` to `&[T]`.
+//! A `b` that is still unconstrained is left alone, as before.
+//!
 //! ## Subtler note
 //!
 //! However, right now, if the user manually specifies the
@@ -62,24 +69,31 @@
 
 use check::{Diverges, FnCtxt};
 
+use lint;
 use rustc::hir;
+use rustc::hir::def::Def;
 use rustc::hir::def_id::DefId;
+use rustc::hir::map as hir_map;
 use rustc::infer::{Coercion, InferResult, InferOk};
 use rustc::infer::type_variable::TypeVariableOrigin;
-use rustc::traits::{self, ObligationCause, ObligationCauseCode};
+use rustc::session::config::CoercionOrder;
+use rustc::traits::{self, ObligationCause, ObligationCauseCode, Reveal};
 use rustc::ty::adjustment::{Adjustment, Adjust, AutoBorrow};
 use rustc::ty::{self, LvaluePreference, TypeAndMut,
                 Ty, ClosureSubsts};
 use rustc::ty::fold::TypeFoldable;
+use rustc::ty::layout::{Align, Size};
 use rustc::ty::error::TypeError;
 use rustc::ty::relate::RelateResult;
-use rustc::ty::subst::Subst;
+use rustc::ty::subst::{Subst, Substs};
 use errors::DiagnosticBuilder;
 use syntax::abi;
+use syntax::ast;
 use syntax::feature_gate;
 use syntax::ptr::P;
 use syntax_pos;
 
+use std::cell::{Cell, RefCell};
 use std::collections::VecDeque;
 use std::ops::Deref;
 
@@ -87,6 +101,38 @@ struct Coerce<'a, 'gcx: 'a + 'tcx, 'tcx: 'a> {
     fcx: &'a FnCtxt<'a, 'gcx, 'tcx>,
     cause: ObligationCause<'tcx>,
     use_lub: bool,
+    /// The region of a reborrow made by the current coercion, see
+    /// `coercion_region`.
+    coercion_region: Cell<Option<ty::Region<'tcx>>>,
+    /// How many variables `coercion_region` created that haven't been
+    /// rolled back, reported by `#[rustc_dump_coercions]`.
+    coercion_region_vars: Cell<usize>,
+
+    /// Whether `&[T; n]` may decay to `*const T`. This is only done for
+    /// an actual coercion site, not for casts, which have their own rules
+    /// for arrays, nor for probes, which can't report the feature gate.
+    array_to_pointer: bool,
+
+    /// The strategies `coerce` applied and whether each succeeded, if
+    /// they are being recorded for `#[rustc_dump_coercions]`.
+    attempts: Option<RefCell<Vec<(CoerceStrategy, bool)>>>,
+}
+
+/// The ways `coerce` may try to coerce one type to another, in order of
+/// precedence.
+#[derive(Copy, Clone, Debug)]
+enum CoerceStrategy {
+    Identity,
+    Unsize,
+    UnsafePtr,
+    BorrowedPointer,
+    FnItem,
+    FnPointer,
+    ClosureToFn,
+    ArrayRefToPair,
+    WrapUnit,
+    ReinterpretSlice,
+    Unify,
 }
 
 impl<'a, 'gcx, 'tcx> Deref for Coerce<'a, 'gcx, 'tcx> {
@@ -131,9 +177,47 @@ impl<'f, 'gcx, 'tcx> Coerce<'f, 'gcx, 'tcx> {
             fcx,
             cause,
             use_lub: false,
+            coercion_region: Cell::new(None),
+            coercion_region_vars: Cell::new(0),
+            array_to_pointer: false,
+            attempts: None,
+        }
+    }
+
+    fn record_attempt(&self, strategy: CoerceStrategy, succeeded: bool) {
+        if let Some(ref attempts) = self.attempts {
+            attempts.borrow_mut().push((strategy, succeeded));
         }
     }
 
+    /// Returns the region variable for a reborrow made by this coercion.
+    /// At most one of the strategies tried by `coerce` succeeds, so they
+    /// can all share a single variable rather than creating their own.
+    fn coercion_region(&self) -> ty::Region<'tcx> {
+        if let Some(r) = self.coercion_region.get() {
+            return r;
+        }
+        let r = self.next_region_var(Coercion(self.cause.span));
+        self.coercion_region.set(Some(r));
+        self.coercion_region_vars.set(self.coercion_region_vars.get() + 1);
+        r
+    }
+
+    /// Like `commit_if_ok`, but also forgets a coercion region created by
+    /// `f`, since rolling back the snapshot discards the variable.
+    fn commit_coercion_if_ok<T, E, F>(&self, f: F) -> Result<T, E>
+        where F: FnOnce() -> Result<T, E>
+    {
+        let region = self.coercion_region.get();
+        let region_vars = self.coercion_region_vars.get();
+        let result = self.commit_if_ok(|_| f());
+        if result.is_err() {
+            self.coercion_region.set(region);
+            self.coercion_region_vars.set(region_vars);
+        }
+        result
+    }
+
     fn unify(&self, a: Ty<'tcx>, b: Ty<'tcx>) -> InferResult<'tcx, Ty<'tcx>> {
         self.commit_if_ok(|_| {
             if self.use_lub {
@@ -161,6 +245,9 @@ impl<'f, 'gcx, 'tcx> Coerce<'f, 'gcx, 'tcx> {
         let a = self.shallow_resolve(a);
         debug!("Coerce.tys({:?} => {:?})", a, b);
 
+        // A region from a previous call may have been rolled back since.
+        self.coercion_region.set(None);
+
         // Just ignore error types.
         if a.references_error() || b.references_error() {
             return success(vec![], b, vec![]);
@@ -186,58 +273,159 @@ impl<'f, 'gcx, 'tcx> Coerce<'f, 'gcx, 'tcx> {
             };
         }
 
-        // Consider coercing the subtype to a DST
-        //
-        // NOTE: this is wrapped in a `commit_if_ok` because it creates
-        // a "spurious" type variable, and we don't want to have that
-        // type variable in memory if the coercion fails.
-        let unsize = self.commit_if_ok(|_| self.coerce_unsized(a, b));
-        if unsize.is_ok() {
-            debug!("coerce: unsize successful");
-            return unsize;
+        // Coercing a type to itself is a no-op, and types are interned, so
+        // this is a cheap way to skip the strategies below. References are
+        // the exception: even `&mut T` to `&mut T` must be reborrowed.
+        if a == b && !a.is_region_ptr() {
+            self.record_attempt(CoerceStrategy::Identity, true);
+            return success(vec![], b, vec![]);
         }
-        debug!("coerce: unsize failed");
 
-        // Examine the supertype and consider auto-borrowing.
+        // The coercions to consider, in order of precedence. Each one returns
+        // `None` if it does not apply to `a` and `b`, and the first one that
+        // does apply decides the outcome, whether it succeeds or not. Only
+        // unsizing is speculative: its failure falls through to the rest.
         //
-        // Note: does not attempt to resolve type variables we encounter.
+        // Note: apart from auto-borrowing through an already constrained
+        // variable, these do not attempt to resolve type variables in `b`.
         // See above for details.
-        match b.sty {
-            ty::TyRawPtr(mt_b) => {
-                return self.coerce_unsafe_ptr(a, b, mt_b.mutbl);
-            }
-
-            ty::TyRef(r_b, mt_b) => {
-                return self.coerce_borrowed_pointer(a, b, r_b, mt_b);
+        let b_shallow = self.shallow_resolve(b);
+        let reinterpret = self.reinterpretable_slices(a, b_shallow);
+        let strategies: &[(CoerceStrategy, &Fn() -> Option<CoerceResult<'tcx>>)] = &[
+            // Consider coercing the subtype to a DST
+            //
+            // NOTE: this is wrapped in a `commit_coercion_if_ok` because it creates
+            // a "spurious" type variable, and we don't want to have that
+            // type variable in memory if the coercion fails.
+            (CoerceStrategy::Unsize, &|| {
+                let unsize = self.commit_coercion_if_ok(|| self.coerce_unsized(a, b));
+                if unsize.is_ok() {
+                    debug!("coerce: unsize successful");
+                    Some(unsize)
+                } else {
+                    debug!("coerce: unsize failed");
+                    self.record_attempt(CoerceStrategy::Unsize, false);
+                    None
+                }
+            }),
+
+            // Examine the supertype and consider auto-borrowing.
+            (CoerceStrategy::UnsafePtr, &|| match b.sty {
+                ty::TyRawPtr(mt_b) => Some(self.coerce_unsafe_ptr(a, b, mt_b.mutbl)),
+                _ => None,
+            }),
+            (CoerceStrategy::BorrowedPointer, &|| match b_shallow.sty {
+                ty::TyRef(r_b, mt_b) if reinterpret.is_none() => {
+                    Some(self.coerce_borrowed_pointer(a, b_shallow, r_b, mt_b))
+                }
+                _ => None,
+            }),
+
+            // Function items are coercible to any closure
+            // type; function pointers are not (that would
+            // require double indirection).
+            // Additionally, we permit coercion of function
+            // items to drop the unsafe qualifier.
+            (CoerceStrategy::FnItem, &|| match a.sty {
+                ty::TyFnDef(..) => Some(self.coerce_from_fn_item(a, b)),
+                _ => None,
+            }),
+
+            // We permit coercion of fn pointers to drop the
+            // unsafe qualifier.
+            (CoerceStrategy::FnPointer, &|| match a.sty {
+                ty::TyFnPtr(a_f) => Some(self.coerce_from_fn_pointer(a, a_f, b)),
+                _ => None,
+            }),
+
+            // Non-capturing closures are coercible to
+            // function pointers
+            (CoerceStrategy::ClosureToFn, &|| match a.sty {
+                ty::TyClosure(def_id_a, substs_a) => {
+                    Some(self.coerce_closure_to_fn(a, def_id_a, substs_a, b))
+                }
+                _ => None,
+            }),
+
+            // Shared references to two-element arrays are coercible
+            // to pairs of references to the elements.
+            (CoerceStrategy::ArrayRefToPair, &|| match (&a.sty, &b_shallow.sty) {
+                (&ty::TyRef(r_a, mt_a), &ty::TyTuple(tys, _))
+                        if mt_a.mutbl == hir::MutImmutable && tys.len() == 2 => {
+                    match mt_a.ty.sty {
+                        ty::TyArray(elem_ty, len)
+                                if len.val.to_const_int().and_then(|n| n.to_u64()) == Some(2) => {
+                            Some(self.coerce_array_ref_to_pair(r_a, elem_ty, b))
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }),
+
+            // `()` is coercible to a struct whose only field is `()`,
+            // if that field is visible here.
+            (CoerceStrategy::WrapUnit, &|| match (&a.sty, &b_shallow.sty) {
+                (&ty::TyTuple(tys, _), &ty::TyAdt(adt_def, substs))
+                        if tys.is_empty() && adt_def.is_struct() => {
+                    self.coerce_unit_to_newtype(adt_def, substs, b)
+                }
+                _ => None,
+            }),
+
+            // Shared references to slices are coercible to shared
+            // references to slices of an integer or float type with the
+            // same layout, e.g. `&[u8]` to `&[i8]`.
+            (CoerceStrategy::ReinterpretSlice, &|| {
+                reinterpret.map(|(r_a, elem_b)| self.coerce_reinterpret_slice(r_a, elem_b, b))
+            }),
+        ];
+
+        // Unsizing normally comes before auto-borrowing, but
+        // `-Z coercion-order=borrow-first` swaps the two to allow
+        // experimenting with their precedence. A failed borrow then falls
+        // through to unsizing, the way a failed unsize normally falls
+        // through to borrowing, so only the winner of a tie changes.
+        let (unsize, rest) = strategies.split_at(1);
+        let (borrow, rest) = rest.split_at(2);
+        let order = self.tcx.sess.opts.debugging_opts.coercion_order;
+        if let Some(CoercionOrder::BorrowFirst) = order {
+            for &(strategy, apply) in borrow {
+                let attempt = self.commit_coercion_if_ok(|| match apply() {
+                    Some(result) => result.map(Some),
+                    None => Ok(None),
+                });
+                match attempt {
+                    Ok(Some(ok)) => {
+                        self.record_attempt(strategy, true);
+                        return Ok(ok);
+                    }
+                    Ok(None) => {}
+                    Err(err) => {
+                        self.record_attempt(strategy, false);
+                        return match (unsize[0].1)() {
+                            Some(result) => {
+                                self.record_attempt(CoerceStrategy::Unsize, result.is_ok());
+                                result
+                            }
+                            None => Err(err),
+                        };
+                    }
+                }
             }
-
-            _ => {}
         }
 
-        match a.sty {
-            ty::TyFnDef(..) => {
-                // Function items are coercible to any closure
-                // type; function pointers are not (that would
-                // require double indirection).
-                // Additionally, we permit coercion of function
-                // items to drop the unsafe qualifier.
-                self.coerce_from_fn_item(a, b)
-            }
-            ty::TyFnPtr(a_f) => {
-                // We permit coercion of fn pointers to drop the
-                // unsafe qualifier.
-                self.coerce_from_fn_pointer(a, a_f, b)
-            }
-            ty::TyClosure(def_id_a, substs_a) => {
-                // Non-capturing closures are coercible to
-                // function pointers
-                self.coerce_closure_to_fn(a, def_id_a, substs_a, b)
-            }
-            _ => {
-                // Otherwise, just use unification rules.
-                self.unify_and(a, b, identity)
+        for &(strategy, apply) in unsize.iter().chain(borrow).chain(rest) {
+            if let Some(result) = apply() {
+                self.record_attempt(strategy, result.is_ok());
+                return result;
             }
         }
+
+        // Otherwise, just use unification rules.
+        let result = self.unify_and(a, b, identity);
+        self.record_attempt(CoerceStrategy::Unify, result.is_ok());
+        result
     }
 
     /// Reborrows `&mut A` to `&mut B` and `&(mut) A` to `&B`.
@@ -269,7 +457,6 @@ impl<'f, 'gcx, 'tcx> Coerce<'f, 'gcx, 'tcx> {
         let span = self.cause.span;
 
         let mut first_error = None;
-        let mut r_borrow_var = None;
         let mut autoderef = self.autoderef(span, a);
         let mut found = None;
 
@@ -355,13 +542,7 @@ impl<'f, 'gcx, 'tcx> Coerce<'f, 'gcx, 'tcx> {
             } else if autoderefs == 1 {
                 r_a // [3] above
             } else {
-                if r_borrow_var.is_none() {
-                    // create var lazilly, at most once
-                    let coercion = Coercion(span);
-                    let r = self.next_region_var(coercion);
-                    r_borrow_var = Some(r); // [4] above
-                }
-                r_borrow_var.unwrap()
+                self.coercion_region() // [4] above
             };
             let derefd_ty_a = self.tcx.mk_ref(r,
                                               TypeAndMut {
@@ -451,6 +632,10 @@ impl<'f, 'gcx, 'tcx> Coerce<'f, 'gcx, 'tcx> {
             return Err(TypeError::Mismatch);
         };
 
+        if let Some(nested) = self.coerce_unsized_nested(source, target) {
+            return nested;
+        }
+
         // Note, we want to avoid unnecessary unsizing. We don't want to coerce to
         // a DST unless we have to. This currently comes out in the wash since
         // we can't unify [T] with U. But to properly support DST, we need to allow
@@ -461,8 +646,7 @@ impl<'f, 'gcx, 'tcx> Coerce<'f, 'gcx, 'tcx> {
             (&ty::TyRef(_, mt_a), &ty::TyRef(_, mt_b)) => {
                 coerce_mutbls(mt_a.mutbl, mt_b.mutbl)?;
 
-                let coercion = Coercion(self.cause.span);
-                let r_borrow = self.next_region_var(coercion);
+                let r_borrow = self.coercion_region();
                 Some((Adjustment {
                     kind: Adjust::Deref(None),
                     target: mt_a.ty
@@ -526,19 +710,55 @@ impl<'f, 'gcx, 'tcx> Coerce<'f, 'gcx, 'tcx> {
                                                          &[coerce_target]));
 
         let mut has_unsized_tuple_coercion = false;
+        let mut has_trait_upcasting_coercion = false;
 
         // Keep resolving `CoerceUnsized` and `Unsize` predicates to avoid
         // emitting a coercion in cases like `Foo<$1>` -> `Foo<$2>`, where
         // inference might unify those two inner type variables later.
         let traits = [coerce_unsized_did, unsize_did];
+        let recursion_limit = self.tcx.sess.recursion_limit.get();
         while let Some(obligation) = queue.pop_front() {
             debug!("coerce_unsized resolve step: {:?}", obligation);
+
+            // Each level of struct unsizing adds a nested obligation, so
+            // deeply nested types would otherwise only be stopped by the
+            // overflow check in selection, which aborts compilation.
+            if obligation.recursion_depth >= recursion_limit {
+                let suggested_limit = recursion_limit * 2;
+                struct_span_err!(self.tcx.sess,
+                                 self.cause.span,
+                                 E0642,
+                                 "reached the recursion limit while coercing `{}` to `{}`",
+                                 source,
+                                 target)
+                    .span_label(self.cause.span, "coercion recursion limit reached")
+                    .help(&format!(
+                        "consider adding a `#![recursion_limit=\"{}\"]` attribute to your crate",
+                        suggested_limit))
+                    .emit();
+                // As with object safety violations below, keep the coercion
+                // so the error isn't followed by a type mismatch.
+                break;
+            }
+
             let trait_ref = match obligation.predicate {
                 ty::Predicate::Trait(ref tr) if traits.contains(&tr.def_id()) => {
                     if unsize_did == tr.def_id() {
-                        if let ty::TyTuple(..) = tr.0.input_types().nth(1).unwrap().sty {
-                            debug!("coerce_unsized: found unsized tuple coercion");
-                            has_unsized_tuple_coercion = true;
+                        let unsize_source = self.shallow_resolve(tr.0.self_ty());
+                        let unsize_target =
+                            self.shallow_resolve(tr.0.input_types().nth(1).unwrap());
+                        match (&unsize_source.sty, &unsize_target.sty) {
+                            (_, &ty::TyTuple(..)) => {
+                                debug!("coerce_unsized: found unsized tuple coercion");
+                                has_unsized_tuple_coercion = true;
+                            }
+                            (&ty::TyDynamic(ref data_a, _), &ty::TyDynamic(ref data_b, _))
+                                if data_a.principal().map(|p| p.def_id()) !=
+                                   data_b.principal().map(|p| p.def_id()) => {
+                                debug!("coerce_unsized: found trait upcasting coercion");
+                                has_trait_upcasting_coercion = true;
+                            }
+                            _ => {}
                         }
                     }
                     tr.clone()
@@ -580,9 +800,77 @@ impl<'f, 'gcx, 'tcx> Coerce<'f, 'gcx, 'tcx> {
                                            feature_gate::EXPLAIN_UNSIZED_TUPLE_COERCION);
         }
 
+        if has_trait_upcasting_coercion && !self.tcx.sess.features.borrow().trait_upcasting {
+            feature_gate::emit_feature_err(&self.tcx.sess.parse_sess,
+                                           "trait_upcasting",
+                                           self.cause.span,
+                                           feature_gate::GateIssue::Language,
+                                           feature_gate::EXPLAIN_TRAIT_UPCASTING);
+        }
+
         Ok(coercion)
     }
 
+    /// Coerces `&&[T; n]` to `&&[T]`, or likewise with `&mut` at either
+    /// layer and trait objects instead of slices. The inner pointer can't
+    /// be unsized where it is, as that would change its layout, so it is
+    /// reborrowed and unsized into a temporary, which is then borrowed.
+    /// Returns `None` unless both sides are references to references and
+    /// the inner target is unsized while the inner source is not.
+    fn coerce_unsized_nested(&self,
+                             source: Ty<'tcx>,
+                             target: Ty<'tcx>)
+                             -> Option<CoerceResult<'tcx>> {
+        let (mt_a, mt_b) = match (&source.sty, &self.shallow_resolve(target).sty) {
+            (&ty::TyRef(_, mt_a), &ty::TyRef(_, mt_b)) => (mt_a, mt_b),
+            _ => return None,
+        };
+        let (inner_a, inner_b) = match (&mt_a.ty.sty, &self.shallow_resolve(mt_b.ty).sty) {
+            (&ty::TyRef(_, inner_a), &ty::TyRef(_, inner_b)) => (inner_a, inner_b),
+            _ => return None,
+        };
+        let is_unsized = |ty: Ty<'tcx>| match self.shallow_resolve(ty).sty {
+            ty::TySlice(_) | ty::TyStr | ty::TyDynamic(..) => true,
+            _ => false,
+        };
+        if is_unsized(inner_a.ty) || !is_unsized(inner_b.ty) {
+            return None;
+        }
+
+        if let Err(err) = coerce_mutbls(mt_a.mutbl, mt_b.mutbl) {
+            return Some(Err(err));
+        }
+        // The inner pointer can only be reborrowed mutably through an
+        // outer `&mut`.
+        if inner_b.mutbl == hir::MutMutable && mt_a.mutbl == hir::MutImmutable {
+            return Some(Err(TypeError::Mutability));
+        }
+
+        Some(self.coerce_unsized(mt_a.ty, mt_b.ty).and_then(|ok| {
+            let InferOk { value: (inner_adjustments, inner_target), mut obligations } = ok;
+            let r_borrow = self.next_region_var(Coercion(self.cause.span));
+            let borrowed = self.tcx.mk_ref(r_borrow, ty::TypeAndMut {
+                mutbl: mt_b.mutbl,
+                ty: inner_target
+            });
+
+            let mut adjustments = vec![Adjustment {
+                kind: Adjust::Deref(None),
+                target: mt_a.ty
+            }];
+            adjustments.extend(inner_adjustments);
+            adjustments.push(Adjustment {
+                kind: Adjust::Borrow(AutoBorrow::Ref(r_borrow, mt_b.mutbl)),
+                target: borrowed
+            });
+
+            let InferOk { value: ty, obligations: unify_obligations } =
+                self.unify(borrowed, target)?;
+            obligations.extend(unify_obligations);
+            success(adjustments, ty, obligations)
+        }))
+    }
+
     fn coerce_from_safe_fn<F, G>(&self,
                                  a: Ty<'tcx>,
                                  fn_ty_a: ty::PolyFnSig<'tcx>,
@@ -650,6 +938,182 @@ impl<'f, 'gcx, 'tcx> Coerce<'f, 'gcx, 'tcx> {
         }
     }
 
+    fn coerce_array_ref_to_pair(&self,
+                                r_a: ty::Region<'tcx>,
+                                elem_ty: Ty<'tcx>,
+                                b: Ty<'tcx>)
+                                -> CoerceResult<'tcx> {
+        //! Attempts to coerce `&'a [T; 2]` to `(&'a T, &'a T)` by
+        //! borrowing each element through the array reference, so
+        //! both borrows share its lifetime.
+
+        let elem_ref = self.tcx.mk_imm_ref(r_a, elem_ty);
+        let pair = self.tcx.intern_tup(&[elem_ref, elem_ref], false);
+        debug!("coerce_array_ref_to_pair(pair={:?}, b={:?})", pair, b);
+        let result = self.unify_and(pair, b, simple(Adjust::BorrowArrayElements));
+
+        if result.is_ok() && !self.tcx.sess.features.borrow().array_ref_pair_coercion {
+            feature_gate::emit_feature_err(&self.tcx.sess.parse_sess,
+                                           "array_ref_pair_coercion",
+                                           self.cause.span,
+                                           feature_gate::GateIssue::Language,
+                                           feature_gate::EXPLAIN_ARRAY_REF_PAIR_COERCION);
+        }
+
+        result
+    }
+
+    fn coerce_box_into_raw(&self,
+                           a: Ty<'tcx>,
+                           b: Ty<'tcx>,
+                           mutbl_b: hir::Mutability)
+                           -> CoerceResult<'tcx> {
+        //! Attempts to coerce `Box<T>` to `*mut T` or `*const T` by
+        //! calling `Box::into_raw`. The box is leaked rather than freed:
+        //! whoever receives the pointer takes over the allocation, as
+        //! when a box is handed to C.
+
+        if self.tcx.lang_items().box_into_raw_fn().is_none() {
+            return self.unify_and(a, b, identity);
+        }
+
+        let boxed_ty = a.boxed_ty();
+        let raw_mut = self.tcx.mk_mut_ptr(boxed_ty);
+        let raw = self.tcx.mk_ptr(ty::TypeAndMut { mutbl: mutbl_b, ty: boxed_ty });
+        let result = self.unify_and(raw, b, |target| {
+            let into_raw = Adjustment {
+                kind: Adjust::BoxIntoRaw,
+                target: raw_mut
+            };
+            match mutbl_b {
+                hir::MutMutable => vec![into_raw],
+                hir::MutImmutable => vec![into_raw, Adjustment {
+                    kind: Adjust::MutToConstPointer,
+                    target
+                }],
+            }
+        });
+
+        if result.is_ok() && !self.tcx.sess.features.borrow().box_into_raw_coercion {
+            feature_gate::emit_feature_err(&self.tcx.sess.parse_sess,
+                                           "box_into_raw_coercion",
+                                           self.cause.span,
+                                           feature_gate::GateIssue::Language,
+                                           feature_gate::EXPLAIN_BOX_INTO_RAW_COERCION);
+        }
+
+        result
+    }
+
+    /// If `a` and `b` are shared references to slices of two different
+    /// integer or float types with the same size and alignment, returns
+    /// the region of `a` and the element type of `b`.
+    fn reinterpretable_slices(&self,
+                              a: Ty<'tcx>,
+                              b: Ty<'tcx>)
+                              -> Option<(ty::Region<'tcx>, Ty<'tcx>)> {
+        let (r_a, elem_a, elem_b) = match (&a.sty, &b.sty) {
+            (&ty::TyRef(r_a, mt_a), &ty::TyRef(_, mt_b))
+                    if mt_a.mutbl == hir::MutImmutable && mt_b.mutbl == hir::MutImmutable => {
+                match (&self.shallow_resolve(mt_a.ty).sty, &self.shallow_resolve(mt_b.ty).sty) {
+                    (&ty::TySlice(elem_a), &ty::TySlice(elem_b)) => (r_a, elem_a, elem_b),
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        let (elem_a, elem_b) = (self.shallow_resolve(elem_a), self.shallow_resolve(elem_b));
+        if elem_a == elem_b {
+            return None;
+        }
+        match (self.scalar_size_and_align(elem_a), self.scalar_size_and_align(elem_b)) {
+            (Some(layout_a), Some(layout_b)) if layout_a == layout_b => Some((r_a, elem_b)),
+            _ => None,
+        }
+    }
+
+    /// The size and alignment of an integer or float type. Every bit
+    /// pattern of the right size is a valid value of these types, so
+    /// they can be reinterpreted as each other. `isize` and `usize` are
+    /// left out: whether they match another type depends on the target.
+    fn scalar_size_and_align(&self, ty: Ty<'tcx>) -> Option<(Size, Align)> {
+        match ty.sty {
+            ty::TyInt(ast::IntTy::Is) | ty::TyUint(ast::UintTy::Us) => return None,
+            ty::TyInt(_) | ty::TyUint(_) | ty::TyFloat(_) => {}
+            _ => return None,
+        }
+        let gcx = self.tcx.global_tcx();
+        let ty = match gcx.lift(&ty) {
+            Some(ty) => ty,
+            None => return None,
+        };
+        match ty.layout(gcx, ty::ParamEnv::empty(Reveal::All)) {
+            Ok(layout) => Some((layout.size(gcx), layout.align(gcx))),
+            Err(_) => None,
+        }
+    }
+
+    fn coerce_reinterpret_slice(&self,
+                                r_a: ty::Region<'tcx>,
+                                elem_b: Ty<'tcx>,
+                                b: Ty<'tcx>)
+                                -> CoerceResult<'tcx> {
+        //! Attempts to coerce `&'a [T]` to `&'a [U]` by reinterpreting the
+        //! elements in place. `reinterpretable_slices` has already
+        //! checked that `T` and `U` have the same layout.
+
+        let reinterpreted = self.tcx.mk_imm_ref(r_a, self.tcx.mk_slice(elem_b));
+        let result = self.unify_and(reinterpreted, b, simple(Adjust::ReinterpretSlice));
+
+        if result.is_ok() && !self.tcx.sess.features.borrow().slice_reinterpret_coercion {
+            feature_gate::emit_feature_err(&self.tcx.sess.parse_sess,
+                                           "slice_reinterpret_coercion",
+                                           self.cause.span,
+                                           feature_gate::GateIssue::Language,
+                                           feature_gate::EXPLAIN_SLICE_REINTERPRET_COERCION);
+        }
+
+        result
+    }
+
+    fn coerce_unit_to_newtype(&self,
+                              adt_def: &'tcx ty::AdtDef,
+                              substs: &'tcx Substs<'tcx>,
+                              b: Ty<'tcx>)
+                              -> Option<CoerceResult<'tcx>> {
+        //! Attempts to coerce `()` to a struct `S` whose only field is
+        //! `()`, by constructing `S` around it. Returns `None` if `S`
+        //! has any other shape, or if its field could not be named
+        //! here, so `()` cannot wrap itself in a struct that code
+        //! outside the struct's module could not construct.
+
+        let variant = adt_def.struct_variant();
+        if variant.fields.len() != 1 {
+            return None;
+        }
+        let field = &variant.fields[0];
+        let field_ty = self.field_ty(self.cause.span, field, substs);
+        if !self.shallow_resolve(field_ty).is_nil() {
+            return None;
+        }
+        let (_, def_scope) = self.tcx.adjust(field.name, adt_def.did, self.body_id);
+        if !field.vis.is_accessible_from(def_scope, self.tcx) {
+            return None;
+        }
+
+        debug!("coerce_unit_to_newtype(b={:?})", b);
+        if !self.tcx.sess.features.borrow().unit_newtype_coercion {
+            feature_gate::emit_feature_err(&self.tcx.sess.parse_sess,
+                                           "unit_newtype_coercion",
+                                           self.cause.span,
+                                           feature_gate::GateIssue::Language,
+                                           feature_gate::EXPLAIN_UNIT_NEWTYPE_COERCION);
+        }
+
+        Some(success(simple(Adjust::WrapUnit)(b), b, vec![]))
+    }
+
     fn coerce_closure_to_fn(&self,
                            a: Ty<'tcx>,
                            def_id_a: DefId,
@@ -704,6 +1168,9 @@ impl<'f, 'gcx, 'tcx> Coerce<'f, 'gcx, 'tcx> {
         let (is_ref, mt_a) = match a.sty {
             ty::TyRef(_, mt) => (true, mt),
             ty::TyRawPtr(mt) => (false, mt),
+            ty::TyAdt(def, _) if def.is_box() => {
+                return self.coerce_box_into_raw(a, b, mutbl_b);
+            }
             _ => {
                 return self.unify_and(a, b, identity);
             }
@@ -719,7 +1186,7 @@ impl<'f, 'gcx, 'tcx> Coerce<'f, 'gcx, 'tcx> {
         // representation, we still register an Adjust::DerefRef so that
         // regionck knows that the region for `a` must be valid here.
         if is_ref {
-            self.unify_and(a_unsafe, b, |target| {
+            let result = self.commit_if_ok(|_| self.unify_and(a_unsafe, b, |target| {
                 vec![Adjustment {
                     kind: Adjust::Deref(None),
                     target: mt_a.ty
@@ -727,7 +1194,30 @@ impl<'f, 'gcx, 'tcx> Coerce<'f, 'gcx, 'tcx> {
                     kind: Adjust::Borrow(AutoBorrow::RawPtr(mutbl_b)),
                     target
                 }]
-            })
+            }));
+            match (result, &mt_a.ty.sty) {
+                // `&[T; n]` decays to a pointer to its first element,
+                // which is how C expects to be handed arrays.
+                (Err(_), &ty::TyArray(elem_ty, _)) if self.array_to_pointer => {
+                    let elem_ptr = self.tcx.mk_ptr(ty::TypeAndMut {
+                        mutbl: mutbl_b,
+                        ty: elem_ty,
+                    });
+                    self.unify_and(elem_ptr, b, |target| {
+                        vec![Adjustment {
+                            kind: Adjust::Deref(None),
+                            target: mt_a.ty
+                        }, Adjustment {
+                            kind: Adjust::Borrow(AutoBorrow::RawPtr(mutbl_b)),
+                            target: a_unsafe
+                        }, Adjustment {
+                            kind: Adjust::ArrayToPointer,
+                            target
+                        }]
+                    })
+                }
+                (result, _) => result,
+            }
         } else if mt_a.mutbl != mutbl_b {
             self.unify_and(a_unsafe, b, simple(Adjust::MutToConstPointer))
         } else {
@@ -758,14 +1248,249 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
         }
 
         let cause = self.cause(expr.span, ObligationCauseCode::ExprAssignable);
-        let coerce = Coerce::new(self, cause);
-        let ok = self.commit_if_ok(|_| coerce.coerce(source, target))?;
+        let mut coerce = Coerce::new(self, cause);
+        coerce.array_to_pointer = !self.is_cast_operand(expr);
+        if self.dump_coercions() {
+            coerce.attempts = Some(RefCell::new(vec![]));
+        }
+        let result = self.commit_if_ok(|_| coerce.coerce(source, target));
+        self.record_coercion(&result);
+
+        // For unit testing: report the strategies that were tried, and
+        // how many region variables were kept for reborrows. A coercion
+        // that tried none, e.g. from `!` or an error type, is not reported.
+        if let Some(ref attempts) = coerce.attempts {
+            let attempts: Vec<_> = attempts.borrow().iter().map(|&(strategy, succeeded)| {
+                format!("{:?} {}", strategy, if succeeded { "succeeded" } else { "failed" })
+            }).collect();
+            if !attempts.is_empty() {
+                let region_vars = coerce.coercion_region_vars.get();
+                self.tcx.sess.span_err(expr.span,
+                                       &format!("coercion from `{}` to `{}`: {}; {} region {}",
+                                                source, target, attempts.join(", "),
+                                                region_vars,
+                                                if region_vars == 1 {
+                                                    "variable"
+                                                } else {
+                                                    "variables"
+                                                }));
+            }
+        }
 
-        let (adjustments, _) = self.register_infer_ok_obligations(ok);
+        let (adjustments, _) = self.register_infer_ok_obligations(result?);
+        let decays = adjustments.iter().any(|adjustment| match adjustment.kind {
+            Adjust::ArrayToPointer => true,
+            _ => false,
+        });
+        if decays && !self.tcx.sess.features.borrow().array_to_pointer_coercion {
+            feature_gate::emit_feature_err(&self.tcx.sess.parse_sess,
+                                           "array_to_pointer_coercion",
+                                           expr.span,
+                                           feature_gate::GateIssue::Language,
+                                           feature_gate::EXPLAIN_ARRAY_TO_POINTER_COERCION);
+        }
+        self.lint_mut_to_const_pointer(expr, source, target, &adjustments);
+        self.lint_mut_temporary_array(expr, source, target);
+        if !self.is_cast_operand(expr) {
+            self.record_coercion_site(expr, &adjustments);
+        }
         self.apply_adjustments(expr, adjustments);
         Ok(target)
     }
 
+    /// Lints an implicit `*mut T` to `*const T` coercion of `expr`, for
+    /// code bases that would rather spell it out with an `as` cast. Casts
+    /// that are checked as coercions aren't implicit, so they are skipped.
+    fn lint_mut_to_const_pointer(&self,
+                                 expr: &hir::Expr,
+                                 source: Ty<'tcx>,
+                                 target: Ty<'tcx>,
+                                 adjustments: &[Adjustment<'tcx>]) {
+        let weakens = adjustments.iter().any(|adjustment| match adjustment.kind {
+            Adjust::MutToConstPointer => true,
+            _ => false,
+        });
+        if !weakens || self.is_cast_operand(expr) {
+            return;
+        }
+        self.tcx.lint_node_note(
+            lint::builtin::IMPLICIT_MUT_TO_CONST_POINTER,
+            expr.id,
+            expr.span,
+            &format!("implicit coercion from `{}` to `{}`",
+                     self.ty_to_string(source),
+                     self.ty_to_string(target)),
+            "use an `as` cast to make the change of mutability explicit");
+    }
+
+    /// Lints `&mut [a, b, c]` coerced to `&mut [T]` where the array is a
+    /// temporary that is dropped at the end of the statement, so that
+    /// whatever is written through the slice is lost. Only arguments of
+    /// calls returning `()` are linted: in a `let` initializer the
+    /// temporary lives to the end of the block, in `ch.encode_utf8(&mut
+    /// [0; 4])` the array is read through the result, and in `r.read(&mut
+    /// [0; 1024])` the array is only scratch space for a call made for
+    /// what it returns.
+    fn lint_mut_temporary_array(&self, expr: &hir::Expr, source: Ty<'tcx>, target: Ty<'tcx>) {
+        match (&source.sty, &target.sty) {
+            (&ty::TyRef(_, ty::TypeAndMut { ty: array, mutbl: hir::MutMutable }),
+             &ty::TyRef(_, ty::TypeAndMut { ty: slice, mutbl: hir::MutMutable })) => {
+                match (&array.sty, &slice.sty) {
+                    (&ty::TyArray(..), &ty::TySlice(_)) => {}
+                    _ => return,
+                }
+            }
+            _ => return,
+        }
+        match expr.node {
+            hir::ExprAddrOf(hir::MutMutable, ref inner) => match inner.node {
+                hir::ExprArray(..) | hir::ExprRepeat(..) => {}
+                _ => return,
+            },
+            _ => return,
+        }
+        let parent = self.tcx.hir.get_parent_node(expr.id);
+        let sig = match self.tcx.hir.get(parent) {
+            hir_map::NodeExpr(&hir::Expr { node: hir::ExprCall(ref callee, _), .. }) => {
+                let callee_ty = match self.tables.borrow().expr_ty_opt(callee) {
+                    Some(ty) => self.resolve_type_vars_if_possible(&ty),
+                    None => return,
+                };
+                match callee_ty.sty {
+                    ty::TyFnDef(def_id, _) => self.tcx.fn_sig(def_id),
+                    ty::TyFnPtr(sig) => sig,
+                    _ => return,
+                }
+            }
+            hir_map::NodeExpr(&hir::Expr { node: hir::ExprMethodCall(..), hir_id, .. }) => {
+                let def = self.tables.borrow().type_dependent_defs().get(hir_id).cloned();
+                match def {
+                    Some(Def::Method(def_id)) => self.tcx.fn_sig(def_id),
+                    _ => return,
+                }
+            }
+            _ => return,
+        };
+        if !sig.output().skip_binder().is_nil() {
+            return;
+        }
+        self.tcx.lint_node_note(
+            lint::builtin::MUT_TEMPORARY_ARRAY_COERCION,
+            expr.id,
+            expr.span,
+            &format!("mutable borrow of a temporary array coerced to `{}`",
+                     self.ty_to_string(target)),
+            "the array is dropped at the end of the statement, so changes made through \
+             the slice are lost; bind the array to a variable first");
+    }
+
+    /// Whether `expr` is the operand of an `as` cast, which is checked as
+    /// a coercion but isn't an implicit one.
+    fn is_cast_operand(&self, expr: &hir::Expr) -> bool {
+        let parent = self.tcx.hir.get_parent_node(expr.id);
+        match self.tcx.hir.get(parent) {
+            hir_map::NodeExpr(&hir::Expr { node: hir::ExprCast(..), .. }) => true,
+            _ => false,
+        }
+    }
+
+    /// Records the adjustments applied to `expr` for `-Z dump-coercions`.
+    /// Coercions that leave the expression unchanged are not recorded.
+    fn record_coercion_site(&self, expr: &hir::Expr, adjustments: &[Adjustment<'tcx>]) {
+        if !self.tcx.sess.opts.debugging_opts.dump_coercions || adjustments.is_empty() {
+            return;
+        }
+        let kinds: Vec<_> = adjustments.iter().map(|adjustment| match adjustment.kind {
+            Adjust::NeverToAny => "never-to-any",
+            Adjust::ReifyFnPointer => "reify-fn-pointer",
+            Adjust::UnsafeFnPointer => "unsafe-fn-pointer",
+            Adjust::ClosureFnPointer => "closure-fn-pointer",
+            Adjust::MutToConstPointer => "mut-to-const-pointer",
+            Adjust::ArrayToPointer => "array-to-pointer",
+            Adjust::Box => "box",
+            Adjust::BorrowArrayElements => "borrow-array-elements",
+            Adjust::WrapUnit => "wrap-unit",
+            Adjust::BoxIntoRaw => "box-into-raw",
+            Adjust::ReinterpretSlice => "reinterpret-slice",
+            Adjust::Deref(None) => "deref",
+            Adjust::Deref(Some(_)) => "overloaded-deref",
+            Adjust::Borrow(AutoBorrow::Ref(_, hir::MutImmutable)) => "borrow",
+            Adjust::Borrow(AutoBorrow::Ref(_, hir::MutMutable)) => "borrow-mut",
+            Adjust::Borrow(AutoBorrow::RawPtr(_)) => "raw-borrow",
+            Adjust::Unsize => "unsize",
+        }).collect();
+        self.tcx.sess.record_coercion_site(expr.span, kinds.join(" -> "));
+    }
+
+    /// Counts a call to `Coerce::coerce` for `-Z coercion-stats`.
+    fn record_coercion(&self, result: &CoerceResult<'tcx>) {
+        let stats = &self.tcx.sess.perf_stats;
+        stats.coercion_attempts.set(stats.coercion_attempts.get() + 1);
+        if let Ok(InferOk { value: (ref adjustments, _), .. }) = *result {
+            if !adjustments.is_empty() {
+                stats.coercion_adjustments.set(stats.coercion_adjustments.get() + 1);
+            }
+        }
+    }
+
+    /// Whether the function being checked has `#[rustc_dump_coercions]`.
+    fn dump_coercions(&self) -> bool {
+        if !self.tcx.sess.features.borrow().rustc_attrs {
+            return false;
+        }
+        let item_id = self.tcx.hir.get_parent(self.body_id);
+        self.tcx.has_attr(self.tcx.hir.local_def_id(item_id), "rustc_dump_coercions")
+    }
+
+    /// Attempts to coerce an array `[T; n]`, by value, to `Box<[T]>` by
+    /// moving it into a new box and then unsizing the box. Unlike every
+    /// other coercion this allocates, so it is only tried for the
+    /// initializer of a `let` (see `check_decl_initializer`), never at
+    /// other coercion sites such as function arguments.
+    pub fn try_coerce_by_boxing(&self,
+                                expr: &hir::Expr,
+                                expr_ty: Ty<'tcx>,
+                                target: Ty<'tcx>)
+                                -> bool {
+        let source = self.resolve_type_vars_with_obligations(expr_ty);
+        let target = self.resolve_type_vars_with_obligations(target);
+        debug!("try_coerce_by_boxing({:?} => {:?})", source, target);
+
+        if !target.is_box() {
+            return false;
+        }
+        match (&source.sty, &target.boxed_ty().sty) {
+            (&ty::TyArray(..), &ty::TySlice(_)) => {}
+            _ => return false,
+        }
+
+        let boxed = self.tcx.mk_box(source);
+        let cause = self.cause(expr.span, ObligationCauseCode::ExprAssignable);
+        let coerce = Coerce::new(self, cause);
+        let ok = match self.commit_if_ok(|_| coerce.coerce_unsized(boxed, target)) {
+            Ok(ok) => ok,
+            Err(_) => return false,
+        };
+
+        if !self.tcx.sess.features.borrow().box_coercion {
+            feature_gate::emit_feature_err(&self.tcx.sess.parse_sess,
+                                           "box_coercion",
+                                           expr.span,
+                                           feature_gate::GateIssue::Language,
+                                           feature_gate::EXPLAIN_BOX_COERCION);
+        }
+
+        let (unsize, _) = self.register_infer_ok_obligations(ok);
+        let mut adjustments = vec![Adjustment {
+            kind: Adjust::Box,
+            target: boxed,
+        }];
+        adjustments.extend(unsize);
+        self.record_coercion_site(expr, &adjustments);
+        self.apply_adjustments(expr, adjustments);
+        true
+    }
+
     /// Same as `try_coerce()`, but without side-effects.
     pub fn can_coerce(&self, expr_ty: Ty<'tcx>, target: Ty<'tcx>) -> bool {
         let source = self.resolve_type_vars_with_obligations(expr_ty);
@@ -833,10 +1558,12 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
             for expr in exprs.iter().map(|e| e.as_coercion_site()).chain(Some(new)) {
                 // The only adjustment that can produce an fn item is
                 // `NeverToAny`, so this should always be valid.
-                self.apply_adjustments(expr, vec![Adjustment {
+                let adjustments = vec![Adjustment {
                     kind: Adjust::ReifyFnPointer,
                     target: fn_ptr
-                }]);
+                }];
+                self.record_coercion_site(expr, &adjustments);
+                self.apply_adjustments(expr, adjustments);
             }
             return Ok(fn_ptr);
         }
@@ -849,9 +1576,11 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
         let mut first_error = None;
         if !self.tables.borrow().adjustments().contains_key(new.hir_id) {
             let result = self.commit_if_ok(|_| coerce.coerce(new_ty, prev_ty));
+            self.record_coercion(&result);
             match result {
                 Ok(ok) => {
                     let (adjustments, target) = self.register_infer_ok_obligations(ok);
+                    self.record_coercion_site(new, &adjustments);
                     self.apply_adjustments(new, adjustments);
                     return Ok(target);
                 }
@@ -891,7 +1620,9 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
             }
         }
 
-        match self.commit_if_ok(|_| coerce.coerce(prev_ty, new_ty)) {
+        let result = self.commit_if_ok(|_| coerce.coerce(prev_ty, new_ty));
+        self.record_coercion(&result);
+        match result {
             Err(_) => {
                 // Avoid giving strange errors on failed attempts.
                 if let Some(e) = first_error {
@@ -907,6 +1638,7 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
                 let (adjustments, target) = self.register_infer_ok_obligations(ok);
                 for expr in exprs {
                     let expr = expr.as_coercion_site();
+                    self.record_coercion_site(expr, &adjustments);
                     self.apply_adjustments(expr, adjustments.clone());
                 }
                 Ok(target)
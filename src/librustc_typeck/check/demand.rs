@@ -11,14 +11,15 @@
 
 use check::FnCtxt;
 use rustc::infer::InferOk;
-use rustc::traits::ObligationCause;
+use rustc::traits::{self, ObligationCause};
 
 use syntax::ast;
 use syntax_pos::{self, Span};
 use rustc::hir;
 use rustc::hir::print;
 use rustc::hir::def::Def;
-use rustc::ty::{self, Ty, AssociatedItem};
+use rustc::ty::{self, Ty, AssociatedItem, ToPredicate};
+use rustc::ty::error::TypeError;
 use errors::{DiagnosticBuilder, CodeMapper};
 
 use super::method::probe;
@@ -94,8 +95,36 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
         if let Err(e) = self.try_coerce(expr, checked_ty, self.diverges.get(), expected) {
             let cause = self.misc(expr.span);
             let expr_ty = self.resolve_type_vars_with_obligations(checked_ty);
+            let abi_mismatch = match e {
+                TypeError::AbiMismatch(values) => Some(values),
+                _ => None,
+            };
+            let array_size_mismatch = match e {
+                TypeError::FixedArraySize(_) => self.array_ref_elem_ty(expr_ty, expected),
+                _ => None,
+            };
+            let array_for_iterator = self.array_for_iterator(expr_ty, expected);
             let mut err = self.report_mismatched_types(&cause, expected, expr_ty, e);
 
+            // Functions are only coercible to function pointers of the
+            // same ABI, which the "expected fn" label alone doesn't say.
+            if let Some(values) = abi_mismatch {
+                err.note(&format!("an `extern {}` function cannot be coerced to an \
+                                   `extern {}` function pointer; only functions with \
+                                   the same ABI can be coerced",
+                                  values.found, values.expected));
+            }
+
+            // A reference to a longer array does not coerce to a reference
+            // to a shorter one; a slice is what accepts any length.
+            if let Some(elem_ty) = array_size_mismatch {
+                err.note(&format!("`{}` cannot be coerced to `{}`: array lengths must \
+                                   match exactly",
+                                  expr_ty, expected));
+                err.help(&format!("use a slice such as `&[{}]` to accept arrays of any length",
+                                  elem_ty));
+            }
+
             // If the expected type is an enum with any variants whose sole
             // field is of the found type, suggest such variants. See Issue
             // #42764.
@@ -123,9 +152,47 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
                 }
             }
 
-            if let Some(suggestion) = self.check_ref(expr,
-                                                     checked_ty,
-                                                     expected) {
+            // An array handed to something taking an iterator object won't
+            // coerce, and borrowing it as `check_ref` suggests doesn't help
+            // either: it has to be iterated over explicitly. `.iter()` only
+            // fits if the items are references to the elements, and a shared
+            // reference to an iterator can't be advanced at all.
+            if let Some((elem_ty, item_ty)) = array_for_iterator {
+                let adapter = match item_ty.sty {
+                    ty::TyRef(_, ty::TypeAndMut { ty, mutbl: hir::MutImmutable })
+                        if ty == elem_ty => Some(".iter()"),
+                    _ if item_ty == elem_ty &&
+                         !self.infcx.type_moves_by_default(self.param_env,
+                                                           elem_ty,
+                                                           expr.span) => {
+                        Some(".iter().cloned()")
+                    }
+                    _ => None,
+                };
+                let sp = self.sess().codemap().call_span_if_macro(expr.span);
+                let src = self.tcx.sess.codemap().span_to_snippet(sp).ok();
+                let suggestion = match (adapter, src, &expected.sty) {
+                    (Some(adapter), Some(src),
+                     &ty::TyRef(_, ty::TypeAndMut { mutbl: hir::MutMutable, .. })) => {
+                        Some(format!("&mut {}{}", src, adapter))
+                    }
+                    (Some(adapter), Some(src), &ty::TyAdt(..)) => {
+                        Some(format!("Box::new({}{})", src, adapter))
+                    }
+                    _ => None,
+                };
+                if let Some(suggestion) = suggestion {
+                    err.help(&format!("arrays are not iterators; try iterating over the \
+                                       elements with `{}`",
+                                      suggestion));
+                }
+            } else if let Some(suggestion) = self.slice_to_array_suggestion(expr,
+                                                                            expr_ty,
+                                                                            expected) {
+                err.help(&suggestion);
+            } else if let Some(suggestion) = self.check_ref(expr,
+                                                            checked_ty,
+                                                            expected) {
                 err.help(&suggestion);
             } else {
                 let mode = probe::Mode::MethodCall;
@@ -138,6 +205,11 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
                     err.help(&format!("here are some functions which \
                                        might fulfill your needs:\n{}",
                                       self.get_best_match(&suggestions).join("\n")));
+                } else if let Some(suggestion) = self.from_impl_suggestion(expr,
+                                                                           expr_ty,
+                                                                           expected) {
+                    // Only when there's nothing more direct to suggest.
+                    err.help(&suggestion);
                 }
             }
             return Some(err);
@@ -145,6 +217,142 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
         None
     }
 
+    /// If `found` is an array and `expected` a reference or box to an
+    /// `Iterator` trait object, returns the element type of the array and
+    /// the `Item` type of the iterator.
+    fn array_for_iterator(&self,
+                          found: Ty<'tcx>,
+                          expected: Ty<'tcx>)
+                          -> Option<(Ty<'tcx>, Ty<'tcx>)> {
+        let elem_ty = match found.sty {
+            ty::TyArray(elem_ty, _) => elem_ty,
+            _ => return None,
+        };
+        let pointee = match expected.sty {
+            ty::TyRef(_, mt) => mt.ty,
+            ty::TyAdt(def, _) if def.is_box() => expected.boxed_ty(),
+            _ => return None,
+        };
+        let predicates = match pointee.sty {
+            ty::TyDynamic(ref predicates, _) => predicates,
+            _ => return None,
+        };
+        match predicates.principal() {
+            Some(principal) => {
+                if self.tcx.lang_items().iterator_trait() != Some(principal.def_id()) {
+                    return None;
+                }
+            }
+            None => return None,
+        }
+        // `Item` is the only associated type of `Iterator`.
+        predicates.projection_bounds().next().map(|item| (elem_ty, item.skip_binder().ty))
+    }
+
+    /// `&[T]` doesn't coerce to `&[T; n]`, as the length of the slice is
+    /// only known at runtime, so suggests the checked conversion through
+    /// `TryInto` instead. That conversion is still unstable, so the help
+    /// also says what it takes to use it.
+    fn slice_to_array_suggestion(&self,
+                                 expr: &hir::Expr,
+                                 found: Ty<'tcx>,
+                                 expected: Ty<'tcx>)
+                                 -> Option<String> {
+        let len = match (&found.sty, &expected.sty) {
+            (&ty::TyRef(_, ty::TypeAndMut { ty: found_ty, mutbl: hir::MutImmutable }),
+             &ty::TyRef(_, ty::TypeAndMut { ty: expected_ty, mutbl: hir::MutImmutable })) => {
+                match (&found_ty.sty, &expected_ty.sty) {
+                    (&ty::TySlice(found_elem), &ty::TyArray(expected_elem, len))
+                        if found_elem == expected_elem => {
+                        match len.val.to_const_int().and_then(|n| n.to_u64()) {
+                            Some(len) => len,
+                            None => return None,
+                        }
+                    }
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+        let sp = self.sess().codemap().call_span_if_macro(expr.span);
+        let src = match self.tcx.sess.codemap().span_to_snippet(sp) {
+            Ok(src) => src,
+            Err(_) => return None,
+        };
+        Some(format!("`{}` cannot be coerced to `{}`; if the slice has exactly {} elements, \
+                      try converting it with `{}.try_into().unwrap()`, which needs \
+                      `#![feature(try_from)]` and `use std::convert::TryInto;`",
+                     found, expected, len, method_receiver(expr, src)))
+    }
+
+    /// A conversion doesn't happen implicitly even where `From` provides
+    /// one, so if `expected` implements `From<found>`, suggests calling
+    /// `.into()`. For `&[T; n]`, an impl of `From<&[T]>` is also looked
+    /// for, as there is no coercion inside the trait lookup.
+    fn from_impl_suggestion(&self,
+                            expr: &hir::Expr,
+                            found: Ty<'tcx>,
+                            expected: Ty<'tcx>)
+                            -> Option<String> {
+        let sp = self.sess().codemap().call_span_if_macro(expr.span);
+        let src = match self.tcx.sess.codemap().span_to_snippet(sp) {
+            Ok(src) => src,
+            Err(_) => return None,
+        };
+
+        if self.implements_from(expected, found) {
+            return Some(format!("`{}` implements `From<{}>`; try converting with `{}.into()`",
+                                expected, found, method_receiver(expr, src)));
+        }
+
+        let slice_ty = match found.sty {
+            ty::TyRef(region, ty::TypeAndMut { ty, mutbl: hir::MutImmutable }) => {
+                match ty.sty {
+                    ty::TyArray(elem_ty, _) => self.tcx.mk_imm_ref(region,
+                                                                   self.tcx.mk_slice(elem_ty)),
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+        if !self.implements_from(expected, slice_ty) {
+            return None;
+        }
+        let slice = if src.starts_with('&') {
+            format!("{}[..]", src)
+        } else {
+            format!("&{}[..]", src)
+        };
+        let conversion = match expected.sty {
+            ty::TyAdt(def, _) => format!("{}::from({})", self.tcx.item_path_str(def.did), slice),
+            _ => format!("({}).into()", slice),
+        };
+        Some(format!("`{}` implements `From<{}>`; try converting a slice of the array with `{}`",
+                     expected, slice_ty, conversion))
+    }
+
+    /// Whether `expected: From<found>` is known to hold. Types that still
+    /// have type inference variables could match all sorts of impls, so
+    /// they never do. Region variables are fine, as any reference in an
+    /// expression has one and they don't affect impl selection.
+    fn implements_from(&self, expected: Ty<'tcx>, found: Ty<'tcx>) -> bool {
+        if expected.has_infer_types() || found.has_infer_types() {
+            return false;
+        }
+        let from_trait = match self.tcx.lang_items().from_trait() {
+            Some(from_trait) => from_trait,
+            None => return false,
+        };
+        let trait_ref = ty::TraitRef {
+            def_id: from_trait,
+            substs: self.tcx.mk_substs_trait(expected, &[found]),
+        };
+        let obligation = traits::Obligation::new(ObligationCause::dummy(),
+                                                 self.param_env,
+                                                 trait_ref.to_predicate());
+        traits::SelectionContext::new(self).evaluate_obligation_conservatively(&obligation)
+    }
+
     fn format_method_suggestion(&self, method: &AssociatedItem) -> String {
         format!("- .{}({})",
                 method.name,
@@ -185,6 +393,21 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
         }
     }
 
+    // If `found` and `expected` are both references to arrays of the same
+    // element type, returns that element type.
+    fn array_ref_elem_ty(&self, found: Ty<'tcx>, expected: Ty<'tcx>) -> Option<Ty<'tcx>> {
+        match (&found.sty, &expected.sty) {
+            (&ty::TyRef(_, found_mt), &ty::TyRef(_, expected_mt)) => {
+                match (&found_mt.ty.sty, &expected_mt.ty.sty) {
+                    (&ty::TyArray(found_elem, _), &ty::TyArray(expected_elem, _))
+                        if found_elem == expected_elem => Some(found_elem),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
     /// This function is used to determine potential "simple" improvements or users' errors and
     /// provide them useful help. For example:
     ///
@@ -298,3 +521,13 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
         }
     }
 }
+
+/// `src`, the snippet of `expr`, as the receiver of a method call.
+fn method_receiver(expr: &hir::Expr, src: String) -> String {
+    match expr.node {
+        hir::ExprPath(..) | hir::ExprCall(..) | hir::ExprMethodCall(..) |
+        hir::ExprField(..) | hir::ExprTupField(..) | hir::ExprIndex(..) |
+        hir::ExprLit(..) => src,
+        _ => format!("({})", src),
+    }
+}
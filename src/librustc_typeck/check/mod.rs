@@ -4182,7 +4182,11 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
             self.demand_eqtype(init.span, init_ty, local_ty);
             init_ty
         } else {
-            self.check_expr_coercable_to_type(init, local_ty)
+            let init_ty = self.check_expr_with_hint(init, local_ty);
+            if !self.try_coerce_by_boxing(init, init_ty, local_ty) {
+                self.demand_coerce(init, init_ty, local_ty);
+            }
+            init_ty
         }
     }
 
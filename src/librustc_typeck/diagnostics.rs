@@ -4606,6 +4606,31 @@ foo.method(); // Ok!
 ```
 "##,
 
+E0642: r##"
+A coercion went through more levels of nested types than the recursion limit
+allows.
+
+Unsizing a struct unsizes its last field, so unsizing a deeply nested type
+takes one step for every level of nesting. The compiler will only take as many
+steps as the recursion limit (which can be set via the `recursion_limit`
+attribute).
+
+For a somewhat artificial example:
+
+```compile_fail,E0642
+#![recursion_limit="4"]
+
+struct Wrap<T: ?Sized>(T);
+
+fn unsize(x: &Wrap<Wrap<Wrap<Wrap<[u8; 1]>>>>) -> &Wrap<Wrap<Wrap<Wrap<[u8]>>>> {
+    x // error, reached the recursion limit while coercing
+}
+```
+
+One fix may be to increase the recursion limit. Otherwise, reduce the nesting
+of the types being coerced.
+"##,
+
 }
 
 register_diagnostics! {
@@ -404,6 +404,28 @@ declare_features! (
 
     // `crate` as visibility modifier, synonymous to `pub(crate)`
     (active, crate_visibility_modifier, "1.23.0", Some(45388)),
+
+    // Allows `let b: Box<[T]> = array;`, boxing the array implicitly.
+    (active, box_coercion, "1.23.0", None),
+
+    // Allows coercing `&[T; 2]` to `(&T, &T)`.
+    (active, array_ref_pair_coercion, "1.23.0", None),
+
+    // Allows coercing `()` to a struct whose only field is `()`.
+    (active, unit_newtype_coercion, "1.23.0", None),
+
+    // Allows coercing `Box<T>` to `*mut T` or `*const T`, leaking the box.
+    (active, box_into_raw_coercion, "1.23.0", None),
+
+    // Allows coercing `&[T]` to `&[U]` for integer or float types `T` and
+    // `U` of the same size and alignment, reinterpreting the elements.
+    (active, slice_reinterpret_coercion, "1.23.0", None),
+
+    // Allows coercing a trait object to an object of one of its supertraits.
+    (active, trait_upcasting, "1.23.0", None),
+
+    // Allows coercing `&[T; n]` to `*const T`, pointing at the first element.
+    (active, array_to_pointer_coercion, "1.23.0", None),
 );
 
 declare_features! (
@@ -682,6 +704,12 @@ pub const BUILTIN_ATTRIBUTES: &'static [(&'static str, AttributeType, AttributeG
                                       is just used for rustc unit tests \
                                       and will never be stable",
                                      cfg_fn!(rustc_attrs))),
+    ("rustc_dump_coercions", Normal, Gated(Stability::Unstable,
+                                           "rustc_attrs",
+                                           "the `#[rustc_dump_coercions]` attribute \
+                                            is just used for rustc unit tests \
+                                            and will never be stable",
+                                           cfg_fn!(rustc_attrs))),
     ("rustc_error", Whitelisted, Gated(Stability::Unstable,
                                        "rustc_attrs",
                                        "the `#[rustc_error]` attribute \
@@ -1161,6 +1189,28 @@ pub const EXPLAIN_PLACEMENT_IN: &'static str =
 pub const EXPLAIN_UNSIZED_TUPLE_COERCION: &'static str =
     "Unsized tuple coercion is not stable enough for use and is subject to change";
 
+pub const EXPLAIN_BOX_COERCION: &'static str =
+    "implicitly boxing an array in a `let` initializer is experimental";
+
+pub const EXPLAIN_ARRAY_REF_PAIR_COERCION: &'static str =
+    "coercing an array reference to a pair of references is experimental";
+
+pub const EXPLAIN_UNIT_NEWTYPE_COERCION: &'static str =
+    "coercing `()` to a struct wrapping `()` is experimental";
+
+pub const EXPLAIN_BOX_INTO_RAW_COERCION: &'static str =
+    "coercing a `Box` to a raw pointer, which leaks the box, is experimental";
+
+pub const EXPLAIN_SLICE_REINTERPRET_COERCION: &'static str =
+    "coercing a slice to a slice of another element type, which reinterprets its \
+     elements, is experimental";
+
+pub const EXPLAIN_TRAIT_UPCASTING: &'static str =
+    "coercing a trait object to a supertrait object is experimental";
+
+pub const EXPLAIN_ARRAY_TO_POINTER_COERCION: &'static str =
+    "coercing an array reference to a pointer to its first element is experimental";
+
 struct PostExpansionVisitor<'a> {
     context: &'a Context<'a>,
 }
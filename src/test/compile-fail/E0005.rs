@@ -12,4 +12,6 @@ fn main() {
     let x = Some(1);
     let Some(y) = x; //~ ERROR E0005
     //~| NOTE pattern `None` not covered
+    //~| NOTE this variant does not match all values
+    //~| NOTE variant `None` of `std::option::Option<i32>` is not covered
 }
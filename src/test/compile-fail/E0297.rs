@@ -14,4 +14,6 @@ fn main() {
     for Some(x) in xs {}
     //~^ ERROR E0005
     //~| NOTE pattern `None` not covered
+    //~| NOTE this variant does not match all values
+    //~| NOTE variant `None` of `std::option::Option<i32>` is not covered
 }
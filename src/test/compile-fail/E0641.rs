@@ -0,0 +1,18 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+fn first_of_some((Some(x), _): (Option<u8>, u8)) -> u8 { //~ ERROR E0641
+    x
+}
+
+fn main() {
+    let f = |Some(y): Option<u8>| y; //~ ERROR E0641
+    f(Some(first_of_some((Some(1), 2))));
+}
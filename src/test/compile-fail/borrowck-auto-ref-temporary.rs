@@ -0,0 +1,46 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A temporary that is only borrowed by an autoderef has no `&` to point
+// at, so the error should say that the borrow was implicit. Method
+// receivers keep the usual label.
+
+use std::rc::Rc;
+
+struct Meters {
+    value: u32,
+}
+
+fn autoderef() {
+    let v: &u32;
+    v = &Rc::new(Meters { value: 5 }).value;
+    //~^ ERROR borrowed value does not live long enough
+    //~| NOTE temporary value implicitly borrowed here
+    //~| NOTE temporary value dropped here while still borrowed
+    //~| NOTE consider using a `let` binding to increase its lifetime
+    println!("{}", v);
+}
+//~^ NOTE temporary value needs to live until here
+
+fn receiver() {
+    let s: &str;
+    s = String::from("hello").as_str();
+    //~^ ERROR borrowed value does not live long enough
+    //~| NOTE temporary value created here
+    //~| NOTE temporary value dropped here while still borrowed
+    //~| NOTE consider using a `let` binding to increase its lifetime
+    println!("{}", s);
+}
+//~^ NOTE temporary value needs to live until here
+
+fn main() {
+    autoderef();
+    receiver();
+}
@@ -0,0 +1,26 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A reference to an array does not coerce to a reference to a shorter
+// array; the error should say so and point at slices instead.
+
+fn first_two(xs: &[i32; 2]) -> i32 {
+    xs[0] + xs[1]
+}
+
+fn main() {
+    let xs = [1, 2, 3];
+    first_two(&xs);
+    //~^ ERROR mismatched types
+    //~| NOTE expected an array with a fixed size of 2 elements, found one with 3 elements
+    //~| NOTE expected type `&[i32; 2]`
+    //~| NOTE `&[i32; 3]` cannot be coerced to `&[i32; 2]`: array lengths must match exactly
+    //~| HELP use a slice such as `&[i32]` to accept arrays of any length
+}
@@ -0,0 +1,22 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A shared reference to an array does not decay to a `*mut` element
+// pointer, and the element types still have to match.
+
+#![feature(array_to_pointer_coercion)]
+
+fn main() {
+    let arr = [1, 2, 3, 4];
+    let _: *mut i32 = &arr; //~ ERROR mismatched types
+
+    let mut arr = [1u8; 4];
+    let _: *const i32 = &mut arr; //~ ERROR mismatched types
+}
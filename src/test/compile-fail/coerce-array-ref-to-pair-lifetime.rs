@@ -0,0 +1,22 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(array_ref_pair_coercion)]
+
+// The element references borrow the array, so they can't outlive it.
+
+fn main() {
+    let pair: (&u8, &u8);
+    {
+        let array = [1, 2];
+        pair = &array; //~ ERROR `array` does not live long enough
+    }
+    let _ = pair;
+}
@@ -0,0 +1,19 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(array_ref_pair_coercion)]
+
+// Only shared references to arrays of exactly two elements are coerced.
+
+fn main() {
+    let _: (&u8, &u8) = &[1, 2, 3]; //~ ERROR mismatched types
+    let _: (&mut u8, &mut u8) = &mut [1, 2]; //~ ERROR mismatched types
+    let _: (&u8, &u8, &u8) = &[1, 2, 3]; //~ ERROR mismatched types
+}
@@ -0,0 +1,25 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(box_coercion)]
+
+// Boxing an array allocates, so it's only done for `let` initializers and
+// not at other coercion sites.
+
+fn takes(_: Box<[u8]>) {}
+
+fn returns() -> Box<[u8]> {
+    [1, 2] //~ ERROR mismatched types
+}
+
+fn main() {
+    takes([1, 2]); //~ ERROR mismatched types
+    let _: Box<[u8]> = [1, 2];
+}
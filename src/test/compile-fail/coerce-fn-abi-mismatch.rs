@@ -0,0 +1,26 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Passing an `extern "C"` function where a Rust function pointer is
+// expected should explain that only same-ABI functions coerce.
+
+extern "C" fn callback() {}
+
+fn call(f: fn()) {
+    f()
+}
+
+fn main() {
+    call(callback);
+    //~^ ERROR mismatched types
+    //~| NOTE expected "Rust" fn, found "C" fn
+    //~| NOTE expected type `fn()`
+    //~| NOTE only functions with the same ABI can be coerced
+}
@@ -0,0 +1,22 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Mutable slices stay invariant in their element type: otherwise a
+// shorter-lived reference could be written into a `&'static` slot.
+
+fn shorten<'a, 'b>(xs: &'a mut [&'static str]) -> &'a mut [&'b str] {
+    xs //~ ERROR mismatched types
+}
+
+fn lengthen<'a, 'b>(xs: &'a mut [&'b str]) -> &'a mut [&'static str] {
+    xs //~ ERROR mismatched types
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A blanket impl whose bounds don't hold doesn't give a type a vtable.
+
+trait Tr {}
+
+impl<T: Copy> Tr for T {}
+
+struct NotCopy;
+
+fn main() {
+    let _: &Tr = &0u8;
+    let _: &Tr = &NotCopy; //~ ERROR E0277
+    let _: Box<Tr> = Box::new(String::new()); //~ ERROR E0277
+}
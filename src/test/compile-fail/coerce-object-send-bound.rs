@@ -0,0 +1,24 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unsizing to a `Send` trait object checks that the source type is `Send`.
+
+use std::rc::Rc;
+
+trait Foo {}
+
+struct HasRc(Rc<u32>);
+
+impl Foo for HasRc {}
+
+fn main() {
+    let _: Box<Foo + Send> = Box::new(HasRc(Rc::new(1)));
+    //~^ ERROR the trait bound `std::rc::Rc<u32>: std::marker::Send` is not satisfied
+}
@@ -0,0 +1,30 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Slices are only reinterpreted as slices of an integer or float type
+// with the same size and alignment, and only through shared references.
+// `isize` and `usize` are never reinterpreted, since their size depends
+// on the target.
+
+#![feature(slice_reinterpret_coercion)]
+
+fn main() {
+    let bytes: &[u8] = &[1, 2];
+    let _: &[u16] = bytes; //~ ERROR mismatched types
+    let _: &[bool] = bytes; //~ ERROR mismatched types
+
+    let words: &[u64] = &[1, 2];
+    let _: &[usize] = words; //~ ERROR mismatched types
+    let sizes: &[usize] = &[1, 2];
+    let _: &[isize] = sizes; //~ ERROR mismatched types
+
+    let mut more = [1u8, 2];
+    let _: &mut [i8] = &mut more[..]; //~ ERROR mismatched types
+}
@@ -0,0 +1,28 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(unit_newtype_coercion)]
+
+mod inner {
+    pub struct Sealed(());
+
+    pub fn inside() -> Sealed {
+        let s: Sealed = (); // the field is visible here
+        s
+    }
+}
+
+struct Two((), ());
+
+fn main() {
+    let _: inner::Sealed = inner::inside();
+    let _: inner::Sealed = (); //~ ERROR mismatched types
+    let _: Two = (); //~ ERROR mismatched types
+}
@@ -0,0 +1,20 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// An `unsafe fn` item must not coerce to a safe `fn` pointer, which could
+// then be called outside of an `unsafe` block.
+
+unsafe fn read(p: *const u32) -> u32 {
+    *p
+}
+
+fn main() {
+    let _: fn(*const u32) -> u32 = read; //~ ERROR mismatched types
+}
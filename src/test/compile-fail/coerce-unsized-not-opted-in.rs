@@ -0,0 +1,18 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A smart pointer only unsizes its contents if it implements `CoerceUnsized`.
+
+struct MyBox<T: ?Sized>(Box<T>);
+
+fn main() {
+    let a = MyBox(Box::new([1u8, 2, 3]));
+    let _: MyBox<[u8]> = a; //~ ERROR mismatched types
+}
@@ -0,0 +1,24 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unsizing a type nested past the recursion limit is reported as a single
+// coercion error instead of an overflow that aborts compilation.
+
+struct Wrap<T: ?Sized>(T);
+
+type W10<T> = Wrap<Wrap<Wrap<Wrap<Wrap<Wrap<Wrap<Wrap<Wrap<Wrap<T>>>>>>>>>>;
+
+type Deep<T> = W10<W10<W10<W10<W10<W10<W10<T>>>>>>>;
+
+fn unsize(x: &Deep<[u8; 1]>) -> &Deep<[u8]> {
+    x //~ ERROR E0642
+}
+
+fn main() {}
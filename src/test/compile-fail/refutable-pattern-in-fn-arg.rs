@@ -10,6 +10,6 @@
 
 fn main() {
     let f = |3: isize| println!("hello");
-    //~^ ERROR refutable pattern in function argument: `_` not covered
+    //~^ ERROR refutable pattern in function argument: `0isize` not covered
     f(4);
 }
@@ -0,0 +1,18 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A `ref` binding doesn't hide the refutability of the rest of the pattern.
+
+fn func((ref a, 1): (u8, u8)) { } //~ ERROR E0641
+
+fn main() {
+    let (ref a, 1) = (1, 2); //~ ERROR E0005
+    let ref mut b @ Some(_) = None::<u8>; //~ ERROR E0005
+}
@@ -0,0 +1,27 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A single-variant enum is only irrefutable if its fields are.
+
+enum Wrapper {
+    Wrapper(u32),
+}
+
+struct Newtype(Wrapper);
+
+fn func(Wrapper::Wrapper(1): Wrapper) { }
+//~^ ERROR refutable pattern in function argument: `Wrapper(0u32)` not covered [E0641]
+
+fn main() {
+    let Wrapper::Wrapper(1) = Wrapper::Wrapper(1);
+    //~^ ERROR refutable pattern in local binding: `Wrapper(0u32)` not covered [E0005]
+    let Newtype(Wrapper::Wrapper(2...5)) = Newtype(Wrapper::Wrapper(3));
+    //~^ ERROR refutable pattern in local binding: `Newtype(Wrapper(0u32))` not covered [E0005]
+}
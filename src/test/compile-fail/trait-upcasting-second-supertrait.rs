@@ -0,0 +1,37 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Only the first supertrait (and its first supertrait, and so on) has a
+// vtable that is a prefix of the object's vtable.
+
+#![feature(trait_upcasting)]
+
+trait A {
+    fn a(&self) {}
+}
+
+trait B {
+    fn b(&self) {}
+}
+
+trait C: A + B {}
+
+impl A for () {}
+
+impl B for () {}
+
+impl C for () {}
+
+fn main() {
+    let c: &C = &();
+    let _a: &A = c;
+    let _b: &B = c;
+    //~^ ERROR mismatched types
+}
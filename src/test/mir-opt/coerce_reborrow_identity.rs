@@ -0,0 +1,34 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Coercing `&T` to the same `&T` needs no adjustment, so MIR building
+// copies the reference instead of reborrowing `*x`.
+
+fn identity(x: &u32) -> &u32 {
+    x
+}
+
+fn main() {
+    let a = 1;
+    identity(&a);
+}
+
+// END RUST SOURCE
+// START rustc.node4.EraseRegions.after.mir
+// fn identity(_1: &u32) -> &u32 {
+//     ...
+//     bb0: {
+//         ...
+//         _0 = _1;
+//         ...
+//         return;
+//     }
+// }
+// END rustc.node4.EraseRegions.after.mir
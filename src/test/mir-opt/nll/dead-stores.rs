@@ -0,0 +1,32 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:-Znll -Zdump-dead-stores
+
+#![allow(warnings)]
+
+fn use_x(_: usize) -> bool { true }
+
+fn main() {
+    let mut x = 22;
+    // Key point: the initializer above is overwritten before it is read.
+    x = 55;
+    use_x(x);
+}
+
+// END RUST SOURCE
+// START rustc.node12.nll.0.mir
+//        | Live variables at bb0[1]: []
+//        | Dead store candidate at bb0[1]: _1
+//        _1 = const 22usize;
+//        | Live variables at bb0[2]: []
+//        _1 = const 55usize;
+//        | Live variables at bb0[3]: [_1]
+// END rustc.node12.nll.0.mir
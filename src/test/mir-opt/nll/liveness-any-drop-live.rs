@@ -0,0 +1,28 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:-Znll
+
+#![allow(warnings)]
+
+fn use_x(_: usize) -> bool { true }
+
+fn main() {
+    // Key point: only `s` ever needs dropping. `x` and the temporaries
+    // for the call to `use_x` are never drop-live.
+    let s = String::new();
+    let x = 22;
+    use_x(x);
+}
+
+// END RUST SOURCE
+// START rustc.node12.nll.0.mir
+// | Drop-live locals: [_1]
+// END rustc.node12.nll.0.mir
@@ -0,0 +1,40 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:-Znll
+
+#![allow(warnings)]
+#![feature(core_intrinsics)]
+
+fn check(x: usize) -> usize {
+    // Key point: `small` is only read by `assume`, which generates no
+    // code, but it is still an ordinary call operand and keeps the
+    // local live up to the intrinsic.
+    let small = x < 10;
+    unsafe { std::intrinsics::assume(small) };
+    x
+}
+
+fn main() {
+    check(3);
+}
+
+// END RUST SOURCE
+// START rustc.node4.nll.0.mir
+//    bb0: {
+//        ...
+//        | Live variables at bb0[7]: [_1, _2]
+//        _5 = _2;
+//        | Live variables at bb0[8]: [_1, _5]
+//        | Live variables before terminator of bb0: [_1, _5]
+//        | Live variables after terminator of bb0: [_1]
+//        _4 = const std::intrinsics::assume(_5) -> bb1;
+//    }
+// END rustc.node4.nll.0.mir
@@ -0,0 +1,44 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:-Znll
+
+#![allow(warnings)]
+
+fn use_x(_: usize) -> bool { true }
+
+fn main() {
+    // Key point: the argument `_3` dies at the call, and its result `b`
+    // only becomes live after it.
+    let x = 22;
+    let b = use_x(x);
+    if b { }
+}
+
+// END RUST SOURCE
+// START rustc.node12.nll.0.mir
+//    | Live variables on entry to bb0: []
+//    bb0: {
+//        | Live variables at bb0[0]: []
+//        StorageLive(_1);
+//        | Live variables at bb0[1]: []
+//        _1 = const 22usize;
+//        | Live variables at bb0[2]: [_1]
+//        StorageLive(_2);
+//        | Live variables at bb0[3]: [_1]
+//        StorageLive(_3);
+//        | Live variables at bb0[4]: [_1]
+//        _3 = _1;
+//        | Live variables at bb0[5]: [_3]
+//        | Live variables before terminator of bb0: [_3]
+//        | Live variables after terminator of bb0: [_2]
+//        _2 = const use_x(_3) -> bb1;
+//    }
+// END rustc.node12.nll.0.mir
@@ -0,0 +1,33 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:-Znll
+
+#![allow(warnings)]
+
+fn use_x(_: usize) -> bool { true }
+
+fn main() {
+    let x = 22;
+    let c = || use_x(x);
+    // Key point: the closure `_2` holds the borrow of `x` and stays live
+    // until its call below. `x` itself is not used again, so its borrow is
+    // kept alive by the closure's region rather than by liveness of `_1`.
+    use_x(1);
+    c();
+}
+
+// END RUST SOURCE
+// START rustc.node12.nll.0.mir
+//        | Live variables before terminator of bb0: [_2]
+//        | Live variables after terminator of bb0: [_2]
+//        _4 = const use_x(const 1usize) -> bb1;
+//    }
+// END rustc.node12.nll.0.mir
@@ -0,0 +1,42 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:-Znll
+
+#![allow(warnings)]
+
+fn use_x(_: usize) -> bool { true }
+
+fn main() {
+    // Key point: the `bool` results of the first two calls are written
+    // but never read, so they are never live, and only `x` is live
+    // across the calls.
+    let x = 22;
+    use_x(1);
+    use_x(2);
+    use_x(x);
+}
+
+// END RUST SOURCE
+// START rustc.node12.nll.0.mir
+//    ...
+//    | Live variables on entry to bb1: [_1]
+//    bb1: {
+//        | Live variables at bb1[0]: [_1]
+//        StorageDead(_2);
+//        | Live variables at bb1[1]: [_1]
+//        StorageLive(_3);
+//        | Live variables at bb1[2]: [_1]
+//        | Live variables before terminator of bb1: [_1]
+//        | Live variables after terminator of bb1: [_1]
+//        _3 = const use_x(const 2usize) -> bb2;
+//    }
+//    | Live variables on entry to bb2: [_1]
+// END rustc.node12.nll.0.mir
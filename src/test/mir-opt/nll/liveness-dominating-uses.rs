@@ -0,0 +1,35 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:-Znll
+
+#![allow(warnings)]
+
+fn check(x: usize, c: bool) -> bool {
+    // Key point: `x` is used in both branches, and neither use dominates
+    // the other, while `c` is only used once, to branch.
+    if c {
+        use_x(x)
+    } else {
+        use_x(x)
+    }
+}
+
+fn use_x(_: usize) -> bool { true }
+
+fn main() {
+    check(22, true);
+}
+
+// END RUST SOURCE
+// START rustc.node4.nll.0.mir
+// | Dominating uses of _1: [bb1[1], bb2[1]]
+// | Dominating uses of _2: [bb0[1]]
+// END rustc.node4.nll.0.mir
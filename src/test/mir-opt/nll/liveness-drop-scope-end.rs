@@ -0,0 +1,49 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:-Znll
+
+#![allow(warnings)]
+
+fn main() {
+    // Key point: `x` is never used, but the drop at the end of the
+    // scope keeps it (drop-)live until the `drop` terminator.
+    let x = S;
+}
+
+struct S;
+
+impl Drop for S {
+    fn drop(&mut self) { }
+}
+
+// END RUST SOURCE
+// START rustc.node4.nll.0.mir
+//    | Live variables on entry to bb0: []
+//    bb0: {
+//        | Live variables at bb0[0]: []
+//        StorageLive(_1);
+//        | Live variables at bb0[1]: []
+//        _1 = S;
+//        | Live variables at bb0[2]: [_1]
+//        | Unconditionally drop-live at bb0[2]: [_1]
+//        _0 = ();
+//        | Live variables at bb0[3]: [_0, _1]
+//        | Unconditionally drop-live at bb0[3]: [_1]
+//        drop(_1) -> bb1;
+//    }
+//    | Live variables on entry to bb1: [_0]
+//    bb1: {
+//        | Live variables at bb1[0]: [_0]
+//        StorageDead(_1);
+//        | Live variables at bb1[1]: [_0]
+//        return;
+//    }
+// END rustc.node4.nll.0.mir
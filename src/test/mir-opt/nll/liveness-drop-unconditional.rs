@@ -0,0 +1,49 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:-Znll
+
+#![allow(warnings)]
+
+fn use_x(_: usize) -> bool { true }
+
+fn main() {
+    // Key point: `x` is dropped on every path out of `main`, the unwind
+    // path from the call included, so it is unconditionally drop-live
+    // (and needs no drop flag) from its initialization on. `y` needs no
+    // drop at all, so it never shows up as drop-live.
+    let x = S;
+    let y = 22;
+    use_x(y);
+}
+
+struct S;
+
+impl Drop for S {
+    fn drop(&mut self) { }
+}
+
+// END RUST SOURCE
+// START rustc.node12.nll.0.mir
+//    | Live variables on entry to bb0: []
+//    bb0: {
+//        | Live variables at bb0[0]: []
+//        StorageLive(_1);
+//        | Live variables at bb0[1]: []
+//        _1 = S;
+//        | Live variables at bb0[2]: [_1]
+//        | Unconditionally drop-live at bb0[2]: [_1]
+//        StorageLive(_2);
+//        | Live variables at bb0[3]: [_1]
+//        | Unconditionally drop-live at bb0[3]: [_1]
+//        _2 = const 22usize;
+//        | Live variables at bb0[4]: [_1, _2]
+//        | Unconditionally drop-live at bb0[4]: [_1]
+// END rustc.node12.nll.0.mir
@@ -0,0 +1,40 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-emscripten no asm! support
+// compile-flags:-Znll
+
+#![allow(warnings)]
+#![feature(asm)]
+
+unsafe fn check(x: usize) -> usize {
+    let mut y = 0;
+    // Key point: `x` is copied into a temporary that the `asm!` reads, so
+    // the temporary is live up to it. `y` is a read-write output, so the
+    // `asm!` both reads it and writes it, and it is live up to there too.
+    asm!("" : "+r"(y) : "r"(x));
+    y
+}
+
+fn main() {
+    unsafe { check(3); }
+}
+
+// END RUST SOURCE
+// START rustc.node4.nll.0.mir
+//    bb0: {
+//        ...
+//        | Live variables at bb0[3]: [_1, _2]
+//        _3 = _1;
+//        | Live variables at bb0[4]: [_2, _3]
+//        ...
+//        | Live variables at bb0[5]: [_2]
+//        StorageDead(_3);
+// END rustc.node4.nll.0.mir
@@ -0,0 +1,48 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:-Znll -Zdump-liveness-kills
+
+#![allow(warnings)]
+
+fn kills(x: S, n: usize) -> usize {
+    // Key point: `x` is moved into `y`, which is never read and whose
+    // storage then ends, while `n` is copied into the return place,
+    // which `return` uses last.
+    let y = x;
+    n
+}
+
+fn main() {
+    kills(S, 1);
+}
+
+struct S;
+
+// END RUST SOURCE
+// START rustc.node4.nll.0.mir
+//    | Live variables on entry to bb0: [_1, _2]
+//    bb0: {
+//        | Live variables at bb0[0]: [_1, _2]
+//        StorageLive(_3);
+//        | Live variables at bb0[1]: [_1, _2]
+//        | Kills at bb0[1]: [_3: Overwrite, _1: Move]
+//        _3 = _1;
+//        | Live variables at bb0[2]: [_2]
+//        | Kills at bb0[2]: [_0: Overwrite, _2: LastUse]
+//        _0 = _2;
+//        | Live variables at bb0[3]: [_0]
+//        | Kills at bb0[3]: [_3: StorageDead]
+//        StorageDead(_3);
+//        | Live variables at bb0[4]: [_0]
+//        | Kills at bb0[4]: [_0: LastUse]
+//        return;
+//    }
+// END rustc.node4.nll.0.mir
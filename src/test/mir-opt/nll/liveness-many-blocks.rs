@@ -0,0 +1,76 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:-Znll
+
+// Exercises the liveness fixpoint on a function with several hundred
+// basic blocks. Each `step!` expands to a conditional, so the CFG has
+// over 500 blocks, all of which feed `x` back into the loop head.
+//
+// `limit` is only read by the loop condition in `bb1` and after the loop
+// exits to `bb2`, so it is live in the body, starting at `bb3`, solely
+// because of the back edge into `bb1`.
+
+#![allow(warnings)]
+
+fn cond(_: usize) -> bool { true }
+
+macro_rules! step {
+    ($x:ident) => { if cond($x) { $x += 1; } };
+}
+
+macro_rules! step10 {
+    ($x:ident) => {
+        step!($x); step!($x); step!($x); step!($x); step!($x);
+        step!($x); step!($x); step!($x); step!($x); step!($x);
+    };
+}
+
+macro_rules! step100 {
+    ($x:ident) => {
+        step10!($x); step10!($x); step10!($x); step10!($x); step10!($x);
+        step10!($x); step10!($x); step10!($x); step10!($x); step10!($x);
+    };
+}
+
+fn main() {
+    let mut x = 0;
+    let limit = 1000;
+    while x < limit {
+        step100!(x);
+        step100!(x);
+    }
+    cond(x + limit);
+}
+
+// END RUST SOURCE
+// START rustc.node12.nll.0.mir
+//    | Live variables on entry to bb0: []
+//    bb0: {
+//        | Live variables at bb0[0]: []
+//        StorageLive(_1);
+//        | Live variables at bb0[1]: []
+//        _1 = const 0usize;
+//        | Live variables at bb0[2]: [_1]
+//        StorageLive(_2);
+//        | Live variables at bb0[3]: [_1]
+//        _2 = const 1000usize;
+//        | Live variables at bb0[4]: [_1, _2]
+//        goto -> bb1;
+//    }
+//    | Live variables on entry to bb1: [_1, _2]
+//    bb1: {
+//    ...
+//    | Live variables on entry to bb2: [_1, _2]
+//    bb2: {
+//    ...
+//    | Live variables on entry to bb3: [_1, _2]
+//    bb3: {
+// END rustc.node12.nll.0.mir
@@ -0,0 +1,40 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:-Znll
+
+#![allow(warnings)]
+
+fn test(c: bool) {
+    let x = S;
+    if c {
+        let y = x;
+    }
+    // Key point: `x` is drop-live on entry to the join block, but it is
+    // only initialized there if we came through the `else` edge.
+}
+
+struct S;
+
+impl Drop for S {
+    fn drop(&mut self) { }
+}
+
+fn main() {
+    test(true);
+}
+
+// END RUST SOURCE
+// START rustc.node4.nll.0.mir
+//    ...
+//    | Live variables on entry to bb6: [_0, _2]
+//    | Maybe-drop-live on entry to bb6: [_2]
+//    bb6: {
+// END rustc.node4.nll.0.mir
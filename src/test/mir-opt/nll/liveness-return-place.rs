@@ -0,0 +1,41 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:-Znll
+
+#![allow(warnings)]
+
+fn ret(x: usize) -> usize {
+    // Key point: the return place `_0` is live from its assignment until
+    // the `return` that hands its value back.
+    let y = x;
+    y
+}
+
+fn main() {
+    ret(22);
+}
+
+// END RUST SOURCE
+// START rustc.node4.nll.0.mir
+//    | Live variables on entry to bb0: [_1]
+//    bb0: {
+//        | Live variables at bb0[0]: [_1]
+//        StorageLive(_2);
+//        | Live variables at bb0[1]: [_1]
+//        _2 = _1;
+//        | Live variables at bb0[2]: [_2]
+//        _0 = _2;
+//        | Live variables at bb0[3]: [_0]
+//        StorageDead(_2);
+//        | Live variables at bb0[4]: [_0]
+//        return;
+//    }
+// END rustc.node4.nll.0.mir
@@ -0,0 +1,39 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:-Znll
+
+#![allow(warnings)]
+
+fn check(x: usize, void: &Void) -> usize {
+    use_x(0);
+    // Key point: the block ending in `unreachable` reads `x`, but control
+    // never gets there, so `x` is not live on entry to it nor at the end
+    // of the call before it.
+    let y = x;
+    match *void {}
+}
+
+fn use_x(_: usize) -> bool { true }
+
+enum Void {}
+
+fn main() {}
+
+// END RUST SOURCE
+// START rustc.node4.nll.0.mir
+//    | Live variables on entry to bb0: []
+//    bb0: {
+//        ...
+//        | Live variables after terminator of bb0: []
+//        ...
+//    | Live variables on entry to bb1: []
+//    bb1: {
+// END rustc.node4.nll.0.mir
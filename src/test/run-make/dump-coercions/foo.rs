@@ -0,0 +1,33 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+fn len(xs: &[i32]) -> usize {
+    xs.len()
+}
+
+fn first(p: *const i32) -> i32 {
+    unsafe { *p }
+}
+
+fn one() -> i32 { 1 }
+fn two() -> i32 { 2 }
+
+fn main() {
+    let array = [1, 2, 3];
+    assert_eq!(len(&array), 3);
+
+    let mut x = 7;
+    let p: *mut i32 = &mut x;
+    assert_eq!(first(p), 7);
+
+    let f: fn() -> i32 = one;
+    let g = if x > 0 { one } else { two };
+    assert_eq!(f() + g(), 2);
+}
@@ -0,0 +1,27 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// `unused` is never read, but debuginfo still describes it; `name` needs
+// dropping and `total` is live across the loop.
+
+fn sum(xs: &[u32]) -> u32 {
+    let unused = 7u32;
+    let name = String::from("sum");
+    let mut total = 0;
+    for &x in xs {
+        total += x;
+    }
+    drop(name);
+    total
+}
+
+fn main() {
+    assert_eq!(sum(&[1, 2, 3]), 6);
+}
@@ -0,0 +1,19 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Casting `&[T; n]` to `*const T` is stable, and doesn't need the
+// `array_to_pointer_coercion` feature that the implicit decay does.
+
+fn main() {
+    let array = [1u32, 2, 3];
+    let p = &array as *const u32;
+    assert_eq!(p, array.as_ptr());
+    assert_eq!(unsafe { *p.offset(2) }, 3);
+}
@@ -0,0 +1,41 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A reference to a struct whose last field is an array coerces to a
+// reference to the same struct with a slice there, whether the struct
+// is a newtype or has other fields before the unsized one.
+
+struct Bytes<T: ?Sized>(T);
+
+struct Tagged<T: ?Sized> {
+    tag: u32,
+    data: T,
+}
+
+fn sum(b: &Bytes<[u8]>) -> u32 {
+    b.0.iter().map(|&x| x as u32).sum()
+}
+
+fn tagged_len(t: &Tagged<[u8]>) -> usize {
+    t.data.len()
+}
+
+fn main() {
+    let b = Bytes([1u8, 2, 3]);
+    assert_eq!(sum(&b), 6);
+
+    let boxed: Box<Bytes<[u8]>> = Box::new(Bytes([4u8, 5]));
+    assert_eq!(sum(&boxed), 9);
+
+    let t = Tagged { tag: 7, data: [0u8; 4] };
+    let u: &Tagged<[u8]> = &t;
+    assert_eq!(u.tag, 7);
+    assert_eq!(tagged_len(u), 4);
+}
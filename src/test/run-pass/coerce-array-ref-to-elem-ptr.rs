@@ -0,0 +1,41 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// `&[T; n]` coerces to `*const T`, and `&mut [T; n]` additionally to
+// `*mut T`, pointing at the first element.
+
+#![feature(array_to_pointer_coercion)]
+
+unsafe fn sum(p: *const i32, len: usize) -> i32 {
+    (0..len).map(|i| *p.offset(i as isize)).sum()
+}
+
+unsafe fn double(p: *mut i32, len: usize) {
+    for i in 0..len {
+        *p.offset(i as isize) *= 2;
+    }
+}
+
+fn main() {
+    let mut arr = [1, 2, 3, 4];
+    unsafe {
+        assert_eq!(sum(&arr, 4), 10);
+        double(&mut arr, 4);
+        assert_eq!(sum(&mut arr, 4), 20);
+    }
+    assert_eq!(arr, [2, 4, 6, 8]);
+
+    let p: *const i32 = &arr;
+    assert_eq!(p, arr.as_ptr());
+
+    // Coercing to a pointer to the whole array still works.
+    let q: *const [i32; 4] = &arr;
+    assert_eq!(q as *const i32, p);
+}
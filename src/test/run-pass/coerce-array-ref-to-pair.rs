@@ -0,0 +1,43 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(array_ref_pair_coercion)]
+
+// A reference to a two-element array coerces to a pair of references to
+// its elements, which live as long as the original reference.
+
+fn sum(pair: (&u32, &u32)) -> u32 {
+    *pair.0 + *pair.1
+}
+
+fn split<'a>(array: &'a [String; 2]) -> (&'a String, &'a String) {
+    array
+}
+
+fn count_calls<'a>(calls: &mut u32, array: &'a [u32; 2]) -> &'a [u32; 2] {
+    *calls += 1;
+    array
+}
+
+fn main() {
+    let array = [1, 2];
+    assert_eq!(sum(&array), 3);
+
+    let strings = [String::from("a"), String::from("b")];
+    let (a, b) = split(&strings);
+    assert_eq!((&a[..], &b[..]), ("a", "b"));
+    assert!(a as *const String == &strings[0] as *const String);
+
+    // The array reference is only evaluated once.
+    let mut calls = 0;
+    let pair: (&u32, &u32) = count_calls(&mut calls, &array);
+    assert_eq!(pair, (&1, &2));
+    assert_eq!(calls, 1);
+}
@@ -0,0 +1,32 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(box_coercion)]
+
+// An array initializer is boxed and unsized when the `let` wants a boxed
+// slice.
+
+fn sum(xs: Box<[u32]>) -> u32 {
+    xs.iter().sum()
+}
+
+fn boxed<T>(array: [T; 2]) -> Box<[T]> {
+    let b: Box<[T]> = array;
+    b
+}
+
+fn main() {
+    let b: Box<[u32]> = [1, 2, 3];
+    assert_eq!(b.len(), 3);
+    assert_eq!(sum(b), 6);
+
+    let strings = boxed([String::from("a"), String::from("b")]);
+    assert_eq!(&*strings, &["a", "b"]);
+}
@@ -0,0 +1,35 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// `Box<[T; n]>` unsizes to `Box<[T]>` wherever a coercion site expects one.
+
+fn total(xs: Box<[u32]>) -> u32 {
+    xs.iter().sum()
+}
+
+fn make() -> Box<[u32]> {
+    Box::new([4, 5, 6])
+}
+
+struct Holder {
+    items: Box<[u32]>,
+}
+
+fn main() {
+    let arr: Box<[u32; 3]> = Box::new([1, 2, 3]);
+    let slice: Box<[u32]> = arr;
+    assert_eq!(&*slice, &[1, 2, 3]);
+
+    assert_eq!(total(Box::new([7, 8])), 15);
+    assert_eq!(make().len(), 3);
+
+    let holder = Holder { items: Box::new([9; 4]) };
+    assert_eq!(holder.items.len(), 4);
+}
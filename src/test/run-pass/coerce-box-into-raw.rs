@@ -0,0 +1,50 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(box_into_raw_coercion)]
+
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+
+static DROPS: AtomicUsize = ATOMIC_USIZE_INIT;
+
+struct Counted(u32);
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        DROPS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+// Takes ownership of the allocation, as a C function would.
+extern "C" fn consume(p: *mut Counted) -> u32 {
+    unsafe { Box::from_raw(p).0 }
+}
+
+extern "C" fn peek(p: *const Counted) -> u32 {
+    unsafe { (*p).0 }
+}
+
+fn main() {
+    // Coercing leaks the box instead of dropping it.
+    assert_eq!(consume(Box::new(Counted(1))), 1);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+
+    let p: *const Counted = Box::new(Counted(2));
+    assert_eq!(peek(p), 2);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+    unsafe { drop(Box::from_raw(p as *mut Counted)); }
+    assert_eq!(DROPS.load(Ordering::SeqCst), 2);
+
+    let bytes: *mut [u8] = vec![1u8, 2, 3].into_boxed_slice();
+    unsafe {
+        assert_eq!(*bytes, [1, 2, 3]);
+        drop(Box::from_raw(bytes));
+    }
+}
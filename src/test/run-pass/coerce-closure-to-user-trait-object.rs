@@ -0,0 +1,41 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A closure coerces to a boxed object of a user trait that closures
+// implement through a blanket impl over the `Fn` traits.
+
+trait Handler {
+    fn handle(&self, x: i32) -> i32;
+}
+
+impl<F: Fn(i32) -> i32> Handler for F {
+    fn handle(&self, x: i32) -> i32 {
+        self(x)
+    }
+}
+
+fn run(h: Box<Handler>, x: i32) -> i32 {
+    h.handle(x)
+}
+
+fn main() {
+    let offset = 10;
+    assert_eq!(run(Box::new(move |x| x + offset), 5), 15);
+
+    let handlers: Vec<Box<Handler>> = vec![
+        Box::new(|x| x * 2),
+        Box::new(move |x| x - offset),
+    ];
+    let results: Vec<i32> = handlers.iter().map(|h| h.handle(7)).collect();
+    assert_eq!(results, [14, -3]);
+
+    let by_ref: &Handler = &|x: i32| x + 1;
+    assert_eq!(by_ref.handle(1), 2);
+}
@@ -0,0 +1,38 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Coercing a reference to an unsized value to a raw pointer keeps its
+// length.
+
+fn slice_len(p: *const [u16]) -> usize {
+    unsafe { (*p).len() }
+}
+
+fn main() {
+    let array = [1u16, 2, 3];
+    let slice: &[u16] = &array;
+    let p: *const [u16] = slice;
+    assert_eq!(slice_len(p), 3);
+    assert_eq!(unsafe { &*p }, &[1, 2, 3][..]);
+    assert_eq!(slice_len(&array[1..]), 2);
+
+    let mut v = vec![4u16, 5];
+    let m: *mut [u16] = &mut v[..];
+    unsafe { (*m)[1] = 6; }
+    assert_eq!(unsafe { (*m).len() }, 2);
+    assert_eq!(v, [4, 6]);
+
+    let s: &str = "héllo";
+    let p: *const str = s;
+    assert_eq!(unsafe { (*p).len() }, 6);
+    assert_eq!(unsafe { &*p }, "héllo");
+    let empty: *const str = "";
+    assert_eq!(unsafe { (*empty).len() }, 0);
+}
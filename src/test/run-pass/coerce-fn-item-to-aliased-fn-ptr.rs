@@ -0,0 +1,28 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Type aliases are expanded before coercion sees the types, so a fn item
+// coerces to any alias of its signature.
+
+type Unary = fn(i32) -> i32;
+type Transform = fn(i32) -> i32;
+
+fn double(x: i32) -> i32 { x * 2 }
+
+fn apply(f: Transform, x: i32) -> i32 { f(x) }
+
+fn main() {
+    let a: Unary = double;
+    let b: Transform = double;
+    assert_eq!(a(3), 6);
+    assert_eq!(b(4), 8);
+    assert_eq!(apply(double, 5), 10);
+    assert_eq!(apply(a, 6), 12);
+}
@@ -0,0 +1,52 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A fn item that is generic over a lifetime keeps its signature
+// higher-ranked when it is coerced, so the result can be applied at
+// different lifetimes.
+
+fn first<'a>(xs: &'a [i32]) -> &'a i32 {
+    &xs[0]
+}
+
+fn apply_twice<F>(f: F) -> (i32, i32)
+    where F: for<'a> Fn(&'a [i32]) -> &'a i32
+{
+    let outer = [1, 2];
+    let a = f(&outer);
+    let b = {
+        let inner = vec![3, 4];
+        *f(&inner)
+    };
+    (*a, b)
+}
+
+fn main() {
+    let ptr: for<'a> fn(&'a [i32]) -> &'a i32 = first;
+    let long = [5, 6];
+    let from_long = ptr(&long);
+    {
+        let short = vec![7];
+        assert_eq!(*ptr(&short), 7);
+    }
+    assert_eq!(*from_long, 5);
+
+    let object: &for<'a> Fn(&'a [i32]) -> &'a i32 = &first;
+    let from_long = object(&long);
+    {
+        let short = vec![8];
+        assert_eq!(*object(&short), 8);
+    }
+    assert_eq!(*from_long, 5);
+
+    assert_eq!(apply_twice(first), (1, 3));
+    assert_eq!(apply_twice(ptr), (1, 3));
+    assert_eq!(apply_twice(object), (1, 3));
+}
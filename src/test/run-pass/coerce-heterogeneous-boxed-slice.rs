@@ -0,0 +1,43 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Each element of an array literal is coerced to the element type of the
+// expected slice, so boxes of different types can share one slice of
+// trait objects.
+
+trait Shape {
+    fn area(&self) -> u32;
+}
+
+struct Square(u32);
+struct Rect(u32, u32);
+
+impl Shape for Square {
+    fn area(&self) -> u32 { self.0 * self.0 }
+}
+
+impl Shape for Rect {
+    fn area(&self) -> u32 { self.0 * self.1 }
+}
+
+fn total(shapes: &[Box<Shape>]) -> u32 {
+    shapes.iter().map(|s| s.area()).sum()
+}
+
+fn main() {
+    let shapes: &[Box<Shape>] = &[Box::new(Square(2)), Box::new(Rect(2, 3))];
+    assert_eq!(shapes[0].area(), 4);
+    assert_eq!(shapes[1].area(), 6);
+
+    assert_eq!(total(&[Box::new(Rect(1, 5)), Box::new(Square(3))]), 14);
+
+    let array: [Box<Shape>; 2] = [Box::new(Rect(4, 1)), Box::new(Square(1))];
+    assert_eq!(total(&array), 5);
+}
@@ -0,0 +1,40 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Coercions between identical types are short-circuited in the type
+// checker. Check that they still type-check, and that `&mut` arguments
+// are still reborrowed rather than moved.
+
+use std::collections::HashMap;
+
+type Complex<'a> = HashMap<String, Vec<(Option<&'a [u8]>, Box<Fn(u32) -> u32>)>>;
+
+fn pass(c: Complex) -> Complex { c }
+
+fn push(v: &mut Vec<u32>, x: u32) {
+    v.push(x);
+}
+
+fn main() {
+    let mut c: Complex = HashMap::new();
+    c.insert("k".to_string(), vec![(None, Box::new(|x| x + 1))]);
+    for _ in 0..100 {
+        c = pass(c);
+    }
+    assert_eq!((c["k"][0].1)(1), 2);
+
+    let mut v = Vec::new();
+    let r = &mut v;
+    for i in 0..3 {
+        push(r, i);
+    }
+    r.push(3);
+    assert_eq!(v, [0, 1, 2, 3]);
+}
@@ -0,0 +1,28 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Mutably borrowing an array that outlives the statement and coercing
+// it to a slice is fine.
+
+#![deny(mut_temporary_array_coercion)]
+
+fn sort(xs: &mut [i32]) {
+    xs.sort();
+}
+
+fn main() {
+    let mut xs = [3, 1, 2];
+    sort(&mut xs);
+    assert_eq!(xs, [1, 2, 3]);
+
+    let ys: &mut [i32] = &mut [6, 5, 4];
+    sort(ys);
+    assert_eq!(ys, [4, 5, 6]);
+}
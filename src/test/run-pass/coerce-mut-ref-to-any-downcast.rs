@@ -0,0 +1,48 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Coercing `&mut T` to `&mut Any` uses the vtable of the concrete `T`, so
+// the `TypeId` it reports lets `downcast_mut` recover the original type,
+// including when `T` comes from a generic parameter.
+
+use std::any::Any;
+
+#[derive(Debug, PartialEq)]
+struct Counter {
+    hits: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Wrapper<T>(T);
+
+fn bump(object: &mut Any) {
+    let counter = object.downcast_mut::<Counter>().unwrap();
+    counter.hits += 1;
+}
+
+fn erase<T: Any>(value: &mut T) -> &mut Any {
+    value
+}
+
+fn main() {
+    let mut counter = Counter { hits: 0 };
+    bump(&mut counter);
+    bump(&mut counter);
+    assert_eq!(counter, Counter { hits: 2 });
+
+    let mut wrapped = Wrapper(7u8);
+    {
+        let object = erase(&mut wrapped);
+        assert!(object.downcast_mut::<Wrapper<u16>>().is_none());
+        assert!(object.downcast_mut::<Counter>().is_none());
+        object.downcast_mut::<Wrapper<u8>>().unwrap().0 += 1;
+    }
+    assert_eq!(wrapped, Wrapper(8));
+}
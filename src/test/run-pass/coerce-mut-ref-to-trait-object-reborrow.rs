@@ -0,0 +1,47 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Coercing `&mut Concrete` to `&mut Trait` reborrows the source for a
+// fresh region while forming the object, so the source reference is
+// still usable once the object is gone.
+
+trait Counter {
+    fn bump(&mut self) -> u32;
+}
+
+struct Steps(u32);
+
+impl Counter for Steps {
+    fn bump(&mut self) -> u32 {
+        self.0 += 1;
+        self.0
+    }
+}
+
+fn bump_twice(c: &mut Counter) -> u32 {
+    c.bump();
+    c.bump()
+}
+
+fn main() {
+    let mut steps = Steps(0);
+    let r = &mut steps;
+
+    // Each call reborrows `r` instead of moving it.
+    assert_eq!(bump_twice(r), 2);
+    assert_eq!(bump_twice(r), 4);
+
+    {
+        let obj: &mut Counter = r;
+        assert_eq!(obj.bump(), 5);
+    }
+    assert_eq!(r.bump(), 6);
+    assert_eq!(steps.0, 6);
+}
@@ -0,0 +1,24 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// `*mut T` coerces to `*const T`.
+
+fn read(p: *const i32) -> i32 {
+    unsafe { *p }
+}
+
+fn main() {
+    let mut x = 5;
+    let p: *mut i32 = &mut x;
+    assert_eq!(read(p), 5);
+
+    let q: *const i32 = p;
+    assert_eq!(unsafe { *q }, 5);
+}
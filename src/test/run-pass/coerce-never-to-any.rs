@@ -0,0 +1,46 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Diverging expressions coerce to whatever type is expected, including
+// references to unsized types, trait objects and `!` itself.
+
+use std::fmt::Display;
+
+fn diverge() -> ! {
+    panic!()
+}
+
+fn still_diverges() -> ! {
+    diverge()
+}
+
+fn int(flag: bool) -> i32 {
+    if flag { 7 } else { panic!() }
+}
+
+fn string(flag: bool) -> &'static str {
+    let s: &str = if flag { "seven" } else { diverge() };
+    s
+}
+
+fn object(flag: bool) -> String {
+    let d: &Display = if flag { &7 } else { panic!("unreachable") };
+    d.to_string()
+}
+
+fn main() {
+    assert_eq!(int(true), 7);
+    assert_eq!(string(true), "seven");
+    assert_eq!(object(true), "7");
+
+    // Never called, but must type check.
+    let _: fn() -> ! = still_diverges;
+    let _ = || -> i32 { return panic!() };
+}
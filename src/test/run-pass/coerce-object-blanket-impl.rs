@@ -0,0 +1,44 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Coercing to a trait object finds the vtable of a blanket impl, and of a
+// blanket impl that only applies through a where clause.
+
+use std::fmt::Debug;
+
+trait Describe {
+    fn describe(&self) -> String;
+}
+
+impl<T: Debug> Describe for T {
+    fn describe(&self) -> String {
+        format!("<{:?}>", self)
+    }
+}
+
+fn describe_all(xs: &[&Describe]) -> Vec<String> {
+    xs.iter().map(|x| x.describe()).collect()
+}
+
+fn generic<T: Debug>(x: &T) -> &Describe {
+    x
+}
+
+fn main() {
+    let a = 1;
+    let b = "b";
+    let object: &Describe = &a;
+    assert_eq!(object.describe(), "<1>");
+    assert_eq!(describe_all(&[&a, &b]), ["<1>", "<\"b\">"]);
+    assert_eq!(generic(&Some(2)).describe(), "<Some(2)>");
+
+    let boxed: Box<Describe> = Box::new(vec![3]);
+    assert_eq!(boxed.describe(), "<[3]>");
+}
@@ -0,0 +1,34 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-emscripten no threads support
+
+// Unsizing to a `Send` trait object succeeds when the source type is `Send`.
+
+use std::sync::Arc;
+use std::thread;
+
+trait Foo {
+    fn get(&self) -> u32;
+}
+
+struct HasArc(Arc<u32>);
+
+impl Foo for HasArc {
+    fn get(&self) -> u32 {
+        *self.0
+    }
+}
+
+fn main() {
+    let b: Box<Foo + Send> = Box::new(HasArc(Arc::new(7)));
+    let v = thread::spawn(move || b.get()).join().unwrap();
+    assert_eq!(v, 7);
+}
@@ -0,0 +1,43 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A `&mut Trait` can be used wherever a `&Trait` is expected: it is
+// reborrowed immutably, and stays usable mutably afterwards.
+
+trait Counter {
+    fn get(&self) -> u32;
+    fn bump(&mut self);
+}
+
+struct C(u32);
+
+impl Counter for C {
+    fn get(&self) -> u32 { self.0 }
+    fn bump(&mut self) { self.0 += 1; }
+}
+
+fn read(c: &Counter) -> u32 {
+    c.get()
+}
+
+fn main() {
+    let mut c = C(0);
+    let m: &mut Counter = &mut c;
+    m.bump();
+    assert_eq!(read(m), 1);
+    let r: &Counter = m;
+    assert_eq!(r.get(), 1);
+    m.bump();
+    assert_eq!(m.get(), 2);
+
+    // Unsizing and downgrading at the same time.
+    let mut d = C(5);
+    assert_eq!(read(&mut d), 5);
+}
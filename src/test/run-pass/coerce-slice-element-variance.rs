@@ -0,0 +1,35 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Slices are covariant in their element type, so shared slices of
+// references can have the references' lifetimes shortened.
+
+fn shorten<'a, 'b: 'a>(xs: &'a [&'b str]) -> &'a [&'a str] {
+    xs
+}
+
+fn first<'a>(xs: &[&'a str], ys: &[&'a str]) -> &'a str {
+    if xs.is_empty() { ys[0] } else { xs[0] }
+}
+
+fn main() {
+    static WORDS: [&'static str; 2] = ["a", "b"];
+    let local = String::from("c");
+    let statics: &[&'static str] = &WORDS;
+    let locals: &[&str] = &[&local];
+
+    assert_eq!(shorten(statics), ["a", "b"]);
+    assert_eq!(first(statics, locals), "a");
+    assert_eq!(first(&[], locals), "c");
+
+    let boxed: Box<[&'static str]> = Box::new(["d"]);
+    let shortened: &[&str] = &boxed;
+    assert_eq!(shortened, ["d"]);
+}
@@ -0,0 +1,33 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Shared slices of integer and float types coerce to slices of another
+// such type with the same size and alignment, reinterpreting each
+// element in place.
+
+#![feature(slice_reinterpret_coercion)]
+
+fn signed(xs: &[i8]) -> Vec<i8> {
+    xs.to_vec()
+}
+
+fn main() {
+    let bytes: &[u8] = &[1, 255, 128];
+    assert_eq!(signed(bytes), [1, -1, -128]);
+    assert_eq!(signed(&bytes[1..]), [-1, -128]);
+
+    let words = [0u32, 0x3f80_0000];
+    let floats: &[f32] = &words[..];
+    assert_eq!(floats, [0.0, 1.0]);
+
+    let back: &[u32] = floats;
+    assert_eq!(back.as_ptr(), words.as_ptr());
+    assert_eq!(back.len(), 2);
+}
@@ -0,0 +1,25 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// When the expected type is an inference variable that an earlier call has
+// already unified with `&[T]`, auto-borrowing still applies to later ones.
+
+fn pair<T>(_: T, _: T) {}
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    let mut slices = Vec::new();
+    slices.push(&v[..]);
+    slices.push(&v);
+    assert_eq!(slices, [&[1, 2, 3][..], &[1, 2, 3][..]]);
+
+    pair(&v[..], &v);
+}
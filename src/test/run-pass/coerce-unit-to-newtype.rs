@@ -0,0 +1,44 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(unit_newtype_coercion)]
+
+#[derive(Debug, PartialEq)]
+struct Token(());
+
+#[derive(Debug, PartialEq)]
+struct Named { unit: () }
+
+#[derive(Debug, PartialEq)]
+struct Wrap<T>(T);
+
+mod inner {
+    #[derive(Debug, PartialEq)]
+    pub struct Open(pub ());
+}
+
+fn take(t: Token) -> Token {
+    t
+}
+
+fn main() {
+    let t: Token = ();
+    assert_eq!(t, Token(()));
+    assert_eq!(take(()), Token(()));
+
+    let n: Named = ();
+    assert_eq!(n, Named { unit: () });
+
+    let w: Wrap<()> = ();
+    assert_eq!(w, Wrap(()));
+
+    let o: inner::Open = ();
+    assert_eq!(o, inner::Open(()));
+}
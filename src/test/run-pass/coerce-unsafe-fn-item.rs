@@ -0,0 +1,35 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// An `unsafe fn` item coerces to an `unsafe fn` pointer of the same
+// signature, including when its type parameters are inferred from it.
+
+unsafe fn read(p: *const u32) -> u32 {
+    *p
+}
+
+unsafe fn first<T: Copy>(xs: *const T) -> T {
+    *xs
+}
+
+fn call(f: unsafe fn(*const u32) -> u32, x: &u32) -> u32 {
+    unsafe { f(x) }
+}
+
+fn main() {
+    let x = 7;
+    assert_eq!(call(read, &x), 7);
+    assert_eq!(call(first, &x), 7);
+
+    let fs: [unsafe fn(*const u32) -> u32; 2] = [read, first];
+    for f in &fs {
+        assert_eq!(unsafe { f(&x) }, 7);
+    }
+}
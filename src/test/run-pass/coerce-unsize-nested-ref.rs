@@ -0,0 +1,51 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A reference to a reference to an array coerces to a reference to a
+// slice reference. Each layer may go from `&mut` to `&`, but never the
+// other way round.
+
+use std::fmt::Debug;
+
+fn sum(xs: &&[i32]) -> i32 {
+    xs.iter().sum()
+}
+
+fn bump(xs: &mut &mut [i32]) {
+    for x in xs.iter_mut() {
+        *x += 1;
+    }
+}
+
+fn first(xs: &&[i32]) -> i32 {
+    xs[0]
+}
+
+fn show(x: &&Debug) -> String {
+    format!("{:?}", x)
+}
+
+fn main() {
+    let array = [1, 2, 3];
+    let r = &array;
+    assert_eq!(sum(&r), 6);
+    assert_eq!(sum(&&array), 6);
+
+    let mut array = [1, 2, 3];
+    {
+        let mut r = &mut array;
+        bump(&mut r);
+        assert_eq!(first(&r), 2);
+    }
+    assert_eq!(array, [2, 3, 4]);
+
+    let n = 5u8;
+    assert_eq!(show(&&n), "5");
+}
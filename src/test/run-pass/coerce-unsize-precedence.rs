@@ -0,0 +1,29 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Coercing `&mut [T; N]` to `&[T]` could go through either the unsizing
+// or the reborrowing coercion. Unsizing is considered first, and its
+// reborrow must leave the original reference usable afterwards.
+
+fn sum(xs: &[i32]) -> i32 {
+    xs.iter().sum()
+}
+
+fn main() {
+    let mut arr = [1, 2, 3];
+    {
+        let r = &mut arr;
+        assert_eq!(sum(r), 6);
+        r[0] = 4;
+        let s: &[i32] = r;
+        assert_eq!(s.len(), 3);
+    }
+    assert_eq!(sum(&arr), 9);
+}
@@ -0,0 +1,40 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unsizing a struct changes the type of its last field, but that field
+// doesn't have to be visible where the coercion happens.
+
+mod inner {
+    pub struct Wrapper<T: ?Sized>(T);
+
+    pub struct Named<T: ?Sized> {
+        pub len: usize,
+        pub items: T,
+    }
+
+    pub fn wrap(bytes: [u8; 4]) -> Wrapper<[u8; 4]> {
+        Wrapper(bytes)
+    }
+
+    pub fn as_unsized(wrapper: &Wrapper<[u8; 4]>) -> &Wrapper<[u8]> {
+        wrapper
+    }
+}
+
+fn main() {
+    let wrapper = inner::wrap([1, 2, 3, 4]);
+    let _: &inner::Wrapper<[u8]> = inner::as_unsized(&wrapper);
+    let _: &inner::Wrapper<[u8]> = &wrapper;
+    let _: Box<inner::Wrapper<[u8]>> = Box::new(wrapper);
+
+    let named = inner::Named { len: 2, items: [1, 2] };
+    let unsized_named: &inner::Named<[i32]> = &named;
+    assert_eq!(unsized_named.len, unsized_named.items.len());
+}
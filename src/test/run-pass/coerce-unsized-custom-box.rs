@@ -0,0 +1,40 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A user-defined smart pointer that implements `CoerceUnsized` unsizes an
+// inner array to a slice just like `Box` does.
+
+#![feature(unsize, coerce_unsized)]
+
+use std::marker::Unsize;
+use std::ops::{CoerceUnsized, Deref};
+
+struct MyBox<T: ?Sized>(Box<T>);
+
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<MyBox<U>> for MyBox<T> {}
+
+impl<T: ?Sized> Deref for MyBox<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+fn len(xs: MyBox<[u8]>) -> usize {
+    xs.len()
+}
+
+fn main() {
+    let a = MyBox(Box::new([1u8, 2, 3]));
+    let b: MyBox<[u8]> = a;
+    assert_eq!(&*b, &[1, 2, 3]);
+
+    assert_eq!(len(MyBox(Box::new([0; 5]))), 5);
+}
@@ -0,0 +1,43 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Tuple-like variant and struct constructors are fn items, so they can
+// be passed wherever a closure is expected, including when their type
+// parameters are only known from the expected signature.
+
+#[derive(Debug, PartialEq)]
+struct Wrap<T>(T);
+
+fn apply<T, U, F: Fn(T) -> U>(f: F, x: T) -> U {
+    f(x)
+}
+
+fn main() {
+    let v: Vec<Option<u32>> = vec![1, 2, 3].into_iter().map(Some).collect();
+    assert_eq!(v, [Some(1), Some(2), Some(3)]);
+
+    let w: Vec<Wrap<&str>> = vec!["a", "b"].into_iter().map(Wrap).collect();
+    assert_eq!(w, [Wrap("a"), Wrap("b")]);
+
+    let r: Vec<Result<u8, ()>> = vec![4u8].into_iter().map(Ok).collect();
+    assert_eq!(r, [Ok(4)]);
+
+    assert_eq!(apply(Some, 'x'), Some('x'));
+
+    let f: fn(i64) -> Option<i64> = Some;
+    assert_eq!(f(5), Some(5));
+
+    let g: &Fn(bool) -> Wrap<bool> = &Wrap;
+    assert_eq!(g(true), Wrap(true));
+
+    let e: Box<FnMut(u16) -> Result<(), u16>> = Box::new(Err);
+    let mut e = e;
+    assert_eq!(e(7), Err(7));
+}
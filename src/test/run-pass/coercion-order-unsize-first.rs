@@ -0,0 +1,20 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z coercion-order=unsize-first
+
+// Unsizing is tried before auto-borrowing, so `&[i32; 3]` coerces to
+// `&[i32]`. See run-pass/coercion-order-borrow-first.rs.
+
+fn main() {
+    let array = [1, 2, 3];
+    let slice: &[i32] = &array;
+    assert_eq!(slice.len(), 3);
+}
@@ -0,0 +1,57 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Znll
+
+// Locals held across a `yield` are live across it for the NLL pass: a
+// value read after resuming is live for a regular use, and a value only
+// kept to be dropped at the end is drop-live. The NLL checks of storage
+// markers and drops run over the `Yield` terminators here.
+
+#![feature(generators, generator_trait)]
+
+use std::cell::Cell;
+use std::ops::{Generator, GeneratorState};
+
+struct Noisy<'a>(&'a Cell<u32>);
+
+impl<'a> Drop for Noisy<'a> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+fn main() {
+    let drops = Cell::new(0);
+    let mut gen = || {
+        let x = 22;
+        let _guard = Noisy(&drops);
+        yield x;
+        // `x` is read after resuming; `_guard` is only dropped.
+        yield x + 1;
+        x + 2
+    };
+
+    match gen.resume() {
+        GeneratorState::Yielded(22) => {}
+        _ => panic!("expected the first yield"),
+    }
+    assert_eq!(drops.get(), 0);
+    match gen.resume() {
+        GeneratorState::Yielded(23) => {}
+        _ => panic!("expected the second yield"),
+    }
+    assert_eq!(drops.get(), 0);
+    match gen.resume() {
+        GeneratorState::Complete(24) => {}
+        _ => panic!("expected completion"),
+    }
+    assert_eq!(drops.get(), 1);
+}
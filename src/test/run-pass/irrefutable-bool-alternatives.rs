@@ -0,0 +1,40 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A `bool` sub-pattern is exhaustive once both `true` and `false` are
+// covered, whether by a wildcard, a binding or alternatives.
+
+#[derive(Debug, PartialEq)]
+enum Dir { Left, Right }
+
+fn flip(pair: (bool, Dir)) -> (bool, Dir) {
+    match pair {
+        (true, Dir::Left) | (false, Dir::Left) => (true, Dir::Right),
+        (b @ true, Dir::Right) | (b @ false, Dir::Right) => (!b, Dir::Left),
+    }
+}
+
+fn main() {
+    let pair = (true, 7);
+    let (a @ _, x) = pair;
+    assert!(a);
+    assert_eq!(x, 7);
+
+    let (_, y) = (false, 8);
+    assert_eq!(y, 8);
+
+    let value = match (false, 9) {
+        (true, v) | (false, v) => v,
+    };
+    assert_eq!(value, 9);
+
+    assert_eq!(flip((false, Dir::Left)), (true, Dir::Right));
+    assert_eq!(flip((true, Dir::Right)), (false, Dir::Left));
+}
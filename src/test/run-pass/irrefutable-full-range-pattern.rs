@@ -0,0 +1,20 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A range covering every value of its type is irrefutable.
+
+fn func((0...255, x): (u8, u8)) -> u8 { x }
+
+fn main() {
+    let 0...255 = 5u8;
+    let (-128...127, y) = (1i8, 2);
+    assert_eq!(func((3, 4)), 4);
+    assert_eq!(y, 2);
+}
@@ -0,0 +1,31 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// The `irrefutable_match` lint only fires when the first arm matches every
+// value; here it doesn't, so the `unreachable!()` fallback is meaningful.
+
+#![deny(irrefutable_match)]
+
+fn first(pair: (Option<u8>, u8)) -> u8 {
+    match pair {
+        (Some(x), _) => x,
+        _ => unreachable!(),
+    }
+}
+
+fn main() {
+    let opt = Some(3);
+    let v = match opt {
+        Some(v) => v,
+        _ => unreachable!(),
+    };
+    assert_eq!(v, 3);
+    assert_eq!(first((Some(1), 2)), 1);
+}
@@ -0,0 +1,38 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Binding modes never make a pattern refutable.
+
+fn by_ref(ref x: (u8, String)) -> usize { x.1.len() }
+
+fn by_ref_mut((ref a, ref mut b): (u8, u8)) -> u8 {
+    *b += *a;
+    *b
+}
+
+fn main() {
+    let ref x = 5;
+    assert_eq!(*x, 5);
+
+    let mut pair = (1, String::from("a"));
+    {
+        let (ref a, ref mut b) = pair;
+        b.push_str("b");
+        assert_eq!(*a, 1);
+    }
+    assert_eq!(pair.1, "ab");
+
+    let ref mut y @ (_, _) = pair;
+    y.0 = 2;
+    assert_eq!(pair.0, 2);
+
+    assert_eq!(by_ref((0, String::from("abc"))), 3);
+    assert_eq!(by_ref_mut((1, 2)), 3);
+}
@@ -0,0 +1,40 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Constructors of single-variant enums and structs are irrefutable as long
+// as their fields are.
+
+enum Wrapper {
+    Wrapper(u32),
+}
+
+enum Pair {
+    Pair { a: (u8, Option<u8>), b: Wrapper },
+}
+
+struct Newtype(Wrapper);
+
+fn unwrap(Wrapper::Wrapper(x): Wrapper) -> u32 { x }
+
+fn main() {
+    let w = Wrapper::Wrapper(3);
+    let Wrapper::Wrapper(x) = w;
+    assert_eq!(x, 3);
+
+    let Newtype(Wrapper::Wrapper(y)) = Newtype(Wrapper::Wrapper(4));
+    assert_eq!(y, 4);
+
+    let p = Pair::Pair { a: (5, None), b: Wrapper::Wrapper(6) };
+    let Pair::Pair { a: (z, opt), b: Wrapper::Wrapper(_) } = p;
+    assert_eq!(z, 5);
+    assert_eq!(opt, None);
+
+    assert_eq!(unwrap(Wrapper::Wrapper(7)), 7);
+}
@@ -0,0 +1,59 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Znll -Zverify-liveness
+
+// The NLL pass checks its worklist liveness fixpoint against a
+// round-robin one. Exercise nested loops, whose back edges are where
+// the worklist has to revisit blocks it has already seen.
+
+struct D(u32);
+
+impl Drop for D {
+    fn drop(&mut self) {}
+}
+
+fn get(d: &D) -> u32 { d.0 }
+
+fn nested_loops(n: u32) -> u32 {
+    let limit = n;
+    let mut total = 0;
+    let mut i = 0;
+    while i < limit {
+        let d = D(i);
+        let mut j = 0;
+        loop {
+            j += 1;
+            if j > get(&d) {
+                break;
+            }
+            total += j;
+        }
+        i += 1;
+    }
+    total
+}
+
+fn early_exit(v: &[u32]) -> Option<usize> {
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] == 0 {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+fn main() {
+    assert_eq!(nested_loops(3), 0 + 1 + (1 + 2));
+    assert_eq!(early_exit(&[3, 0, 1]), Some(1));
+    assert_eq!(early_exit(&[3, 1]), None);
+}
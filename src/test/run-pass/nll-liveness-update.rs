@@ -0,0 +1,57 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Znll -Zverify-liveness-update
+
+// The NLL pass edits each block in turn, updates the liveness results
+// incrementally and checks them against a full recomputation. Exercise
+// loops, drops and calls, where a stale set could survive an update.
+
+struct D(u32);
+
+impl Drop for D {
+    fn drop(&mut self) {}
+}
+
+fn get(d: &D) -> u32 { d.0 }
+
+fn nested_loops(n: u32) -> u32 {
+    let mut total = 0;
+    let mut i = 0;
+    while i < n {
+        let d = D(i);
+        let mut j = 0;
+        loop {
+            j += 1;
+            if j > get(&d) {
+                break;
+            }
+            total += j;
+        }
+        i += 1;
+    }
+    total
+}
+
+fn branches(x: Option<D>, flag: bool) -> u32 {
+    let fallback = D(7);
+    let value = match x {
+        Some(d) => if flag { get(&d) } else { get(&fallback) },
+        None => 0,
+    };
+    value + get(&fallback)
+}
+
+fn main() {
+    assert_eq!(nested_loops(3), 0 + 1 + (1 + 2));
+    assert_eq!(branches(Some(D(1)), true), 8);
+    assert_eq!(branches(Some(D(1)), false), 14);
+    assert_eq!(branches(None, true), 7);
+}
@@ -0,0 +1,70 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Znll -Zverify-storage-markers
+
+// With `-Z verify-storage-markers`, the NLL pass checks that no local is
+// used outside of its `StorageLive` and `StorageDead`. Exercise the shapes
+// of MIR that scopes, temporaries and early exits produce.
+
+struct D(u32);
+
+impl Drop for D {
+    fn drop(&mut self) {}
+}
+
+fn get(d: &D) -> u32 { d.0 }
+
+fn loops(n: u32) -> u32 {
+    let mut total = 0;
+    let mut i = 0;
+    while i < n {
+        let d = D(i);
+        i += 1;
+        if get(&d) % 2 == 0 {
+            continue;
+        }
+        if get(&d) > 7 {
+            break;
+        }
+        total += get(&D(get(&d)));
+    }
+    loop {
+        let d = D(total);
+        if get(&d) > 0 {
+            break;
+        }
+        total = 1;
+    }
+    total
+}
+
+fn matches(x: Option<D>) -> u32 {
+    match x {
+        Some(ref d) if get(d) > 10 => get(d) - 10,
+        Some(d) => get(&d),
+        None => return 0,
+    }
+}
+
+fn early(x: Result<D, u32>) -> Result<u32, u32> {
+    let d = x?;
+    let e = D(1);
+    Ok(get(&d) + get(&e))
+}
+
+fn main() {
+    assert_eq!(loops(20), 1 + 3 + 5 + 7);
+    assert_eq!(matches(Some(D(12))), 2);
+    assert_eq!(matches(Some(D(3))), 3);
+    assert_eq!(matches(None), 0);
+    assert_eq!(early(Ok(D(2))), Ok(3));
+    assert_eq!(early(Err(4)), Err(4));
+}
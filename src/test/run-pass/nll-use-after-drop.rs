@@ -0,0 +1,60 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Znll -Zverify-drops
+
+// With `-Z verify-drops`, the NLL pass checks that no local is read after
+// a `Drop` of it. The MIR built here drops locals and then assigns them
+// again, replaces them in place and drops them on only some paths, none
+// of which may be reported.
+
+struct D(u32);
+
+impl Drop for D {
+    fn drop(&mut self) {}
+}
+
+fn get(d: &D) -> u32 { d.0 }
+
+fn reassigned(n: u32) -> u32 {
+    let mut total = 0;
+    let mut d;
+    let mut i = 0;
+    while i < n {
+        // `d` is dropped at the end of each iteration before it is
+        // assigned again at the start of the next.
+        d = D(i);
+        total += get(&d);
+        i += 1;
+    }
+    total
+}
+
+fn replaced() -> u32 {
+    let mut d = D(1);
+    d = D(get(&d) + 1);
+    get(&d)
+}
+
+fn moved_on_one_path(x: D, keep: bool) -> u32 {
+    let r = get(&x);
+    if keep {
+        let y = x;
+        return get(&y) + r;
+    }
+    r
+}
+
+fn main() {
+    assert_eq!(reassigned(4), 0 + 1 + 2 + 3);
+    assert_eq!(replaced(), 2);
+    assert_eq!(moved_on_one_path(D(3), true), 6);
+    assert_eq!(moved_on_one_path(D(3), false), 3);
+}
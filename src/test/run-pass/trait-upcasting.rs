@@ -0,0 +1,68 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Objects can be coerced to objects of their supertraits along the chain
+// of first supertraits, keeping the vtable of the original object.
+
+#![feature(trait_upcasting)]
+
+trait Named {
+    fn name(&self) -> String;
+}
+
+trait Greet: Named {
+    fn greet(&self) -> String {
+        format!("hello, {}", self.name())
+    }
+}
+
+trait Shout: Greet {
+    fn shout(&self) -> String;
+}
+
+struct Person(&'static str);
+
+impl Named for Person {
+    fn name(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl Greet for Person {}
+
+impl Shout for Person {
+    fn shout(&self) -> String {
+        self.greet().to_uppercase()
+    }
+}
+
+fn name_of(named: &Named) -> String {
+    named.name()
+}
+
+fn main() {
+    let shout: Box<Shout> = Box::new(Person("ferris"));
+    assert_eq!(shout.shout(), "HELLO, FERRIS");
+
+    let greet: Box<Greet> = shout;
+    assert_eq!(greet.greet(), "hello, ferris");
+
+    let named: Box<Named> = greet;
+    assert_eq!(named.name(), "ferris");
+
+    // Skipping a level, and dropping an auto trait at the same time.
+    let shout: Box<Shout + Send> = Box::new(Person("bors"));
+    let named: Box<Named> = shout;
+    assert_eq!(named.name(), "bors");
+
+    let person = Person("crab");
+    let shout: &Shout = &person;
+    assert_eq!(name_of(shout), "crab");
+}
@@ -0,0 +1,43 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Capacity overflow while allocating a vector (as `Clone for Vec` does
+// through `to_vec`) is an ordinary panic that unwinds, just like a panic
+// from an element's `clone`.
+
+// ignore-emscripten no threads support
+
+use std::any::Any;
+use std::panic;
+use std::usize;
+
+fn message(payload: &Box<Any + Send>) -> &str {
+    if let Some(s) = payload.downcast_ref::<String>() {
+        return s;
+    }
+    if let Some(s) = payload.downcast_ref::<&'static str>() {
+        return s;
+    }
+    panic!("unexpected panic payload")
+}
+
+fn main() {
+    let r = panic::catch_unwind(|| {
+        Vec::<u32>::with_capacity(usize::MAX);
+    });
+    assert_eq!(message(&r.unwrap_err()), "capacity overflow");
+
+    let v = vec![1u32, 2, 3];
+    let r = panic::catch_unwind(|| {
+        let mut w = v.clone();
+        w.reserve(usize::MAX);
+    });
+    assert_eq!(message(&r.unwrap_err()), "capacity overflow");
+}
@@ -0,0 +1,55 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Cloning a `Vec` of `Copy` elements copies the buffer directly through
+// the `copy_from_slice` specialization of `extend_from_slice`, so the
+// elements' own `clone` is never called. Other elements are still
+// cloned one by one.
+
+use std::cell::Cell;
+
+thread_local!(static CLONES: Cell<usize> = Cell::new(0));
+
+fn clones() -> usize {
+    CLONES.with(|c| c.get())
+}
+
+#[derive(Copy, Debug, PartialEq)]
+struct Plain(u32);
+
+impl Clone for Plain {
+    fn clone(&self) -> Plain {
+        CLONES.with(|c| c.set(c.get() + 1));
+        *self
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Counted(u32);
+
+impl Clone for Counted {
+    fn clone(&self) -> Counted {
+        CLONES.with(|c| c.set(c.get() + 1));
+        Counted(self.0)
+    }
+}
+
+fn main() {
+    let plain = vec![Plain(1), Plain(2), Plain(3)];
+    let copied = plain.clone();
+    assert_eq!(copied, plain);
+    assert_eq!(plain[..].to_vec(), plain);
+    assert_eq!(clones(), 0);
+
+    let counted = vec![Counted(1), Counted(2), Counted(3)];
+    let cloned = counted.clone();
+    assert_eq!(cloned, counted);
+    assert_eq!(clones(), 3);
+}
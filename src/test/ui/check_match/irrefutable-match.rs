@@ -0,0 +1,45 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+struct Point { x: i32, y: i32 }
+
+fn main() {
+    let p = Point { x: 1, y: 2 };
+    let sum = match p {
+        Point { x, y } => x + y,
+        _ => unreachable!(),
+    };
+    assert_eq!(sum, 3);
+    assert_eq!(allowed(Point { x: 3, y: 4 }), 7);
+    assert_eq!(custom::sum(Point { x: 5, y: 6 }), 11);
+}
+
+// With the lint allowed, the fallback arm is still reported as unreachable.
+#[allow(irrefutable_match)]
+fn allowed(p: Point) -> i32 {
+    match p {
+        Point { x, y } => x + y,
+        _ => unreachable!(),
+    }
+}
+
+// Only the standard library's `unreachable!()` counts.
+mod custom {
+    macro_rules! unreachable {
+        () => { panic!("not the real one") }
+    }
+
+    pub fn sum(p: super::Point) -> i32 {
+        match p {
+            super::Point { x, y } => x + y,
+            _ => unreachable!(),
+        }
+    }
+}
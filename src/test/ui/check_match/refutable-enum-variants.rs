@@ -0,0 +1,23 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+enum Three { A(u8), B, C { x: u8 } }
+
+enum Six { A, B, C, D, E, F }
+
+fn main() {
+    let three = Three::B;
+    let Three::A(_) = three;
+    let Three::C { .. } = three;
+    let six = Six::A;
+    let Six::A = six;
+}
@@ -0,0 +1,38 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// An array passed where an iterator object is expected should point at
+// `.iter()`, not suggest borrowing the array.
+
+fn total(iter: &mut Iterator<Item=&i32>) -> i32 {
+    iter.fold(0, |acc, x| acc + x)
+}
+
+fn count(iter: Box<Iterator<Item=&'static i32>>) -> usize {
+    iter.count()
+}
+
+// Items by value need the elements copied out.
+fn sum(iter: &mut Iterator<Item=i32>) -> i32 {
+    iter.sum()
+}
+
+// A shared reference can't drive the iterator, so nothing is suggested.
+fn inspect(_: &Iterator<Item=&i32>) {}
+
+static YS: [i32; 3] = [4, 5, 6];
+
+fn main() {
+    let xs = [1i32, 2, 3];
+    total(xs); //~ ERROR mismatched types
+    count(YS); //~ ERROR mismatched types
+    sum(xs); //~ ERROR mismatched types
+    inspect(xs); //~ ERROR mismatched types
+}
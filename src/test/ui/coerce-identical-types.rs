@@ -0,0 +1,28 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Coercions between identical types skip every strategy, and so create
+// no region variables. References are still reborrowed.
+
+#![feature(rustc_attrs)]
+
+type Complex = (Vec<Option<u8>>, [u32; 4]);
+
+#[rustc_dump_coercions]
+fn pass(c: Complex) -> Complex {
+    c
+}
+
+#[rustc_dump_coercions]
+fn reborrow(v: &mut Vec<u32>) -> &mut Vec<u32> {
+    v
+}
+
+fn main() {}
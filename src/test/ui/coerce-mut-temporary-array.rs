@@ -0,0 +1,33 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// run-pass
+
+#![warn(mut_temporary_array_coercion)]
+
+use std::io::Read;
+
+fn sort(xs: &mut [i32]) {
+    xs.sort();
+}
+
+fn main() {
+    sort(&mut [3, 1, 2]);
+    //~^ WARN mutable borrow of a temporary array coerced to `&mut [i32]`
+
+    // The temporary lives until the end of the block here.
+    let xs: &mut [i32] = &mut [3, 1, 2];
+    sort(xs);
+    assert_eq!(xs, [1, 2, 3]);
+
+    // A scratch buffer for a call made for what it returns is fine.
+    let mut input: &[u8] = b"hello";
+    assert_eq!(input.read(&mut [0; 1024]).unwrap(), 5);
+}
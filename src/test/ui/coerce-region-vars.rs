@@ -0,0 +1,26 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A successful slice coercion creates a single region variable, for its
+// reborrow.
+
+#![feature(rustc_attrs)]
+
+#[rustc_dump_coercions]
+fn slice(a: &[i32; 3]) -> &[i32] {
+    a
+}
+
+#[rustc_dump_coercions]
+fn mut_slice(a: &mut [i32; 3]) -> &mut [i32] {
+    a
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// `&[T]` doesn't coerce to `&[T; n]`, but the error suggests the checked
+// conversion to an array of the expected length.
+
+fn sum4(xs: &[i32; 4]) -> i32 {
+    xs.iter().sum()
+}
+
+fn main() {
+    let v = vec![1, 2, 3, 4];
+    let slice: &[i32] = &v;
+    sum4(slice); //~ ERROR mismatched types
+    let _quad: &[i32; 4] = &v[..4]; //~ ERROR mismatched types
+}
@@ -0,0 +1,44 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// `.into()` is only suggested when there isn't a more direct fix: adding or
+// removing a borrow, or calling a method that returns the expected type.
+
+struct Meters(u32);
+
+impl<'a> From<&'a Meters> for Meters {
+    fn from(meters: &'a Meters) -> Meters {
+        Meters(meters.0)
+    }
+}
+
+struct Length(usize);
+
+impl Length {
+    fn len(&self) -> usize {
+        self.0
+    }
+}
+
+impl From<Length> for usize {
+    fn from(length: Length) -> usize {
+        length.len()
+    }
+}
+
+fn walk(_: Meters) {}
+
+fn measure(_: usize) {}
+
+fn main() {
+    let meters = Meters(3);
+    walk(&meters); //~ ERROR mismatched types
+    measure(Length(4)); //~ ERROR mismatched types
+}
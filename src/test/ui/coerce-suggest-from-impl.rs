@@ -0,0 +1,33 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A `From` impl on the expected type doesn't make for a coercion, but the
+// error points at it.
+
+struct ArrayView<'a, T: 'a> {
+    items: &'a [T],
+}
+
+impl<'a, T> From<&'a [T]> for ArrayView<'a, T> {
+    fn from(items: &'a [T]) -> ArrayView<'a, T> {
+        ArrayView { items }
+    }
+}
+
+fn total(view: ArrayView<i32>) -> i32 {
+    view.items.iter().sum()
+}
+
+fn main() {
+    let xs = [1, 2, 3];
+    total(&xs); //~ ERROR mismatched types
+    let slice: &[i32] = &xs;
+    total(slice); //~ ERROR mismatched types
+}
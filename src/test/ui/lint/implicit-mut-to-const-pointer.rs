@@ -0,0 +1,22 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(implicit_mut_to_const_pointer)]
+
+fn takes(_: *const u8) {}
+
+fn main() {
+    let mut x = 0u8;
+    let p: *mut u8 = &mut x;
+    let _: *const u8 = p;
+    takes(p);
+    let _ = p as *const u8;
+    takes(p as *const u8);
+}
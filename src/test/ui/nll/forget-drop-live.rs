@@ -0,0 +1,47 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags:-Znll
+
+use std::mem::{self, ManuallyDrop};
+
+struct Noisy;
+
+impl Drop for Noisy {
+    fn drop(&mut self) {}
+}
+
+#[allow(forget_while_drop_live)]
+fn allowed() {
+    let v = Noisy;
+    mem::forget(&v);
+}
+
+fn main() {
+    // Moving a value into `mem::forget` is how it's meant to be used.
+    let x = Noisy;
+    mem::forget(x);
+
+    // Reassigning a forgotten local gives it a new value, dropped as usual.
+    let mut z = Noisy;
+    mem::forget(z);
+    z = Noisy;
+    let _r = &z;
+
+    // Forgetting a reference leaves the value itself to be dropped.
+    let w = Noisy;
+    mem::forget(&w);
+
+    // Forgetting a `ManuallyDrop` is intentional, so no warning here.
+    let y = ManuallyDrop::new(Noisy);
+    mem::forget(&y);
+
+    allowed();
+}